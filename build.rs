@@ -0,0 +1,19 @@
+use std::env;
+use std::process::Command;
+
+fn main() {
+    // str::parse() of floats follows the same grammar as parse_core() since Rust 1.55,
+    // so the tests only compare the two directly from that version on
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rustc-check-cfg=cfg(core_float_grammar)");
+    let rustc = env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+    let minor = Command::new(rustc)
+        .arg("--version")
+        .output()
+        .ok()
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .and_then(|version| version.split('.').nth(1)?.parse::<u32>().ok());
+    if minor >= Some(55) {
+        println!("cargo:rustc-cfg=core_float_grammar");
+    }
+}
@@ -0,0 +1,153 @@
+use core::cmp::Ordering;
+
+/// Fixed-capacity unsigned big integer, just enough to compare a number against
+/// the halfway point between two adjacent floats.
+#[derive(Clone)]
+pub struct Bigint {
+    pub len: usize,
+    pub limbs: [u32; Self::MAX_LIMBS],
+}
+
+impl Bigint {
+    // enough for 2^1076 * 36^31 and the like, with some headroom
+    pub const MAX_LIMBS: usize = 48;
+
+    #[inline]
+    pub fn from_u64(v: u64) -> Self {
+        let mut x = Self {
+            len: 2,
+            limbs: [0; Self::MAX_LIMBS],
+        };
+        x.limbs[0] = v as u32;
+        x.limbs[1] = (v >> 32) as u32;
+        x.normalize();
+        x
+    }
+
+    #[inline]
+    fn normalize(&mut self) {
+        while self.len != 0 && self.limbs[self.len - 1] == 0 {
+            self.len -= 1;
+        }
+    }
+
+    #[inline]
+    fn push(&mut self, limb: u32) {
+        debug_assert!(self.len < Self::MAX_LIMBS);
+        self.limbs[self.len] = limb;
+        self.len += 1;
+    }
+
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    pub fn mul_small(&mut self, y: u32) {
+        let mut carry = 0_u64;
+        for limb in &mut self.limbs[..self.len] {
+            let z = (*limb as u64) * (y as u64) + carry;
+            *limb = z as u32;
+            carry = z >> 32;
+        }
+        if carry != 0 {
+            self.push(carry as u32);
+        }
+    }
+
+    #[inline]
+    pub fn mul_pow2(&mut self, n: u32) {
+        if self.is_zero() {
+            return;
+        }
+        let (limbs, bits) = ((n / 32) as usize, n % 32);
+        if bits != 0 {
+            let mut carry = 0_u32;
+            for limb in &mut self.limbs[..self.len] {
+                let z = *limb;
+                *limb = (z << bits) | carry;
+                carry = z >> (32 - bits);
+            }
+            if carry != 0 {
+                self.push(carry);
+            }
+        }
+        if limbs != 0 {
+            debug_assert!(self.len + limbs <= Self::MAX_LIMBS);
+            self.limbs.copy_within(..self.len, limbs);
+            for limb in &mut self.limbs[..limbs] {
+                *limb = 0;
+            }
+            self.len += limbs;
+        }
+    }
+
+    #[inline]
+    pub fn mul_pow(&mut self, radix: u32, mut n: u32) {
+        // multiply by the largest power of radix fitting into a limb at a time
+        let (mut step, mut step_pow) = (radix, 1);
+        while step <= 0xFFFF_FFFF_u32 / radix {
+            step *= radix;
+            step_pow += 1;
+        }
+        while n >= step_pow {
+            self.mul_small(step);
+            n -= step_pow;
+        }
+        if n != 0 {
+            self.mul_small(radix.pow(n));
+        }
+    }
+
+    #[inline]
+    pub fn sub_assign(&mut self, other: &Self) {
+        // requires self >= other
+        let mut borrow = false;
+        for i in 0..self.len {
+            let y = if i < other.len { other.limbs[i] } else { 0 };
+            let (z, b1) = self.limbs[i].overflowing_sub(y);
+            let (z, b2) = z.overflowing_sub(borrow as u32);
+            self.limbs[i] = z;
+            borrow = b1 || b2;
+        }
+        debug_assert!(!borrow);
+        self.normalize();
+    }
+
+    #[inline]
+    pub fn compare(&self, other: &Self) -> Ordering {
+        self.len.cmp(&other.len).then_with(|| {
+            let (x, y) = (&self.limbs[..self.len], &other.limbs[..other.len]);
+            x.iter().rev().cmp(y.iter().rev())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cmp::Ordering;
+
+    use super::Bigint;
+
+    #[test]
+    fn test_bigint_ops() {
+        let mut x = Bigint::from_u64(0xFFFF_FFFF_FFFF_FFFF);
+        x.mul_small(3);
+        assert_eq!(&x.limbs[..x.len], &[0xFFFF_FFFD, 0xFFFF_FFFF, 2]);
+        x.mul_pow2(36);
+        assert_eq!(&x.limbs[..x.len], &[0, 0xFFFF_FFD0, 0xFFFF_FFFF, 0x2F]);
+
+        let mut y = Bigint::from_u64(1);
+        y.mul_pow(3, 40);
+        assert_eq!(
+            y.compare(&Bigint::from_u64(12_157_665_459_056_928_801)),
+            Ordering::Equal
+        );
+        y.sub_assign(&Bigint::from_u64(12_157_665_459_056_928_800));
+        assert_eq!(y.compare(&Bigint::from_u64(1)), Ordering::Equal);
+        y.sub_assign(&Bigint::from_u64(1));
+        assert!(y.is_zero());
+        assert_eq!(y.compare(&x), Ordering::Less);
+    }
+}
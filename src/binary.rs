@@ -1,14 +1,23 @@
+use core::cmp::Ordering;
+
 use crate::common::AdjustedMantissa;
 use crate::float::Float;
 use crate::table::{LARGEST_POWER_OF_FIVE, POWER_OF_FIVE_128, SMALLEST_POWER_OF_FIVE};
 
+/// Compute the float along with how its magnitude compares to the exact value, which is
+/// `None` if the product is too close to a representable value or a halfway point to tell.
 #[inline]
-pub fn compute_float<F: Float>(q: i64, mut w: u64) -> AdjustedMantissa {
-    let am_zero = AdjustedMantissa::zero_pow2(0);
-    let am_inf = AdjustedMantissa::zero_pow2(F::INFINITE_POWER);
-    let am_error = AdjustedMantissa::zero_pow2(-1);
+pub fn compute_float<F: Float>(q: i64, mut w: u64) -> (AdjustedMantissa, Option<Ordering>) {
+    let am_zero = (AdjustedMantissa::zero_pow2(0), Some(Ordering::Less));
+    let am_inf = (
+        AdjustedMantissa::zero_pow2(F::INFINITE_POWER),
+        Some(Ordering::Greater),
+    );
+    let am_error = (AdjustedMantissa::zero_pow2(-1), None);
 
-    if w == 0 || q < F::SMALLEST_POWER_OF_TEN as i64 {
+    if w == 0 {
+        return (AdjustedMantissa::zero_pow2(0), Some(Ordering::Equal));
+    } else if q < F::SMALLEST_POWER_OF_TEN as i64 {
         return am_zero;
     } else if q > F::LARGEST_POWER_OF_TEN as i64 {
         return am_inf;
@@ -23,18 +32,24 @@ pub fn compute_float<F: Float>(q: i64, mut w: u64) -> AdjustedMantissa {
         }
     }
     let upperbit = (hi >> 63) as i32;
-    let mut mantissa = hi >> (upperbit + 64 - F::MANTISSA_EXPLICIT_BITS as i32 - 3);
+    let shift = upperbit + 64 - F::MANTISSA_EXPLICIT_BITS as i32 - 3;
+    let mut mantissa = hi >> shift;
     let mut power2 = power(q as i32) + upperbit - lz as i32 - F::MINIMUM_EXPONENT;
+    // whether there are nonzero bits below the rounding bit, the last one of the mantissa
+    let mut sticky = (lo > 1 && lo != 0xFFFF_FFFF_FFFF_FFFF) || hi & ((1_u64 << shift) - 1) != 0;
     if power2 <= 0 {
         if -power2 + 1 >= 64 {
             return am_zero;
         }
+        sticky |= mantissa & ((1_u64 << (-power2 + 1)) - 1) != 0;
         mantissa >>= -power2 + 1;
+        let ord = rounding(mantissa, sticky);
         mantissa += mantissa & 1;
         mantissa >>= 1;
         power2 = (mantissa >= (1_u64 << F::MANTISSA_EXPLICIT_BITS)) as i32;
-        return AdjustedMantissa { mantissa, power2 };
+        return (AdjustedMantissa { mantissa, power2 }, ord);
     }
+    let ord = rounding(mantissa, sticky);
     if lo <= 1
         && q >= F::MIN_EXPONENT_ROUND_TO_EVEN as i64
         && q <= F::MAX_EXPONENT_ROUND_TO_EVEN as i64
@@ -53,7 +68,17 @@ pub fn compute_float<F: Float>(q: i64, mut w: u64) -> AdjustedMantissa {
     if power2 >= F::INFINITE_POWER {
         return am_inf;
     }
-    AdjustedMantissa { mantissa, power2 }
+    (AdjustedMantissa { mantissa, power2 }, ord)
+}
+
+#[inline]
+fn rounding(mantissa: u64, sticky: bool) -> Option<Ordering> {
+    // without the sticky bits the number may be exact or halfway, which needs the slow path
+    match (sticky, mantissa & 1) {
+        (false, _) => None,
+        (true, 0) => Some(Ordering::Less),
+        (true, _) => Some(Ordering::Greater),
+    }
 }
 
 #[inline]
@@ -0,0 +1,123 @@
+use crate::float::Float;
+use crate::hex::parse_hex_literal;
+use crate::options::{DigitSeparator, ParseOptions};
+use crate::parse::parse_float;
+
+/// Type suffix of a C, C++ or Java float literal like `1.5f`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CSuffix {
+    /// The `f` or `F` suffix (`float`).
+    Float,
+    /// The `d` or `D` suffix (`double` in Java).
+    Double,
+    /// The `l` or `L` suffix (`long double` in C and C++).
+    LongDouble,
+    /// The `f16` or `F16` suffix (`std::float16_t` in C++23).
+    Float16,
+    /// The `bf16` or `BF16` suffix (`std::bfloat16_t` in C++23).
+    BFloat16,
+}
+
+// longest suffixes first, since they're matched at the end of the literal
+const SUFFIXES: &[(&[u8], CSuffix)] = &[
+    (b"bf16", CSuffix::BFloat16),
+    (b"BF16", CSuffix::BFloat16),
+    (b"f16", CSuffix::Float16),
+    (b"F16", CSuffix::Float16),
+    (b"f", CSuffix::Float),
+    (b"F", CSuffix::Float),
+    (b"d", CSuffix::Double),
+    (b"D", CSuffix::Double),
+    (b"l", CSuffix::LongDouble),
+    (b"L", CSuffix::LongDouble),
+];
+
+const C_OPTIONS: ParseOptions = ParseOptions::new()
+    .allow_leading_plus(false)
+    .allow_special_values(false)
+    .digit_separator(DigitSeparator::permissive(b'\''));
+
+#[inline]
+fn is_ident_nondigit(c: u8) -> bool {
+    // any non-ASCII character is treated as a possible universal character
+    c.is_ascii_alphabetic() || c == b'_' || c >= 0x80
+}
+
+/// Find the extent of a preprocessing number, which must then form a single literal.
+#[inline]
+fn lex_pp_number(s: &[u8]) -> Option<usize> {
+    let mut i = match *s.first()? {
+        c if c.is_ascii_digit() => 1,
+        b'.' if s.len() > 1 && s[1].is_ascii_digit() => 2,
+        _ => return None,
+    };
+    while let Some(&c) = s.get(i) {
+        let next = s.get(i + 1).copied().unwrap_or(0);
+        if matches_exponent_sign(c, next) {
+            i += 2;
+        } else if c.is_ascii_digit() || is_ident_nondigit(c) || c == b'.' {
+            i += 1;
+        } else if c == b'\'' && (next.is_ascii_digit() || is_ident_nondigit(next)) {
+            i += 2;
+        } else {
+            break;
+        }
+    }
+    Some(i)
+}
+
+#[inline]
+fn matches_exponent_sign(c: u8, next: u8) -> bool {
+    (c == b'e' || c == b'E' || c == b'p' || c == b'P') && (next == b'+' || next == b'-')
+}
+
+#[inline]
+fn separators_ok(s: &[u8], hex: bool) -> bool {
+    // a separator must be surrounded by digits, which excludes the 0x prefix
+    let is_digit = |c: u8| c.is_ascii_digit() || (hex && c.is_ascii_hexdigit());
+    s.iter().enumerate().all(|(i, &c)| {
+        c != b'\'' || (i != 0 && i + 1 < s.len() && is_digit(s[i - 1]) && is_digit(s[i + 1]))
+    })
+}
+
+#[inline]
+fn strip_suffix(s: &[u8]) -> (&[u8], Option<CSuffix>) {
+    for &(text, suffix) in SUFFIXES {
+        if s.len() > text.len() && s.ends_with(text) {
+            return (&s[..s.len() - text.len()], Some(suffix));
+        }
+    }
+    (s, None)
+}
+
+#[inline]
+fn parse_body<F: Float>(s: &[u8], hex: bool) -> Option<(F, bool)> {
+    // returns the value along with whether it's a floating literal rather than an integer
+    if hex {
+        let num = parse_hex_literal(s, b'\'')?;
+        return Some((num.to_adjusted_mantissa::<F>().to_float(false), true));
+    }
+    let (value, n) = parse_float(s, &C_OPTIONS)?;
+    if n != s.len() {
+        return None;
+    }
+    let is_float = s.iter().any(|&c| c == b'.' || c == b'e' || c == b'E');
+    Some((value, is_float))
+}
+
+#[inline]
+pub fn parse_c_literal<F: Float>(s: &[u8]) -> Option<(F, usize, Option<CSuffix>)> {
+    let len = lex_pp_number(s)?;
+    let (body, suffix) = strip_suffix(&s[..len]);
+    let hex = body.len() > 2 && body[0] == b'0' && (body[1] == b'x' || body[1] == b'X');
+    if !separators_ok(body, hex) {
+        return None;
+    }
+    let (value, is_float) = parse_body::<F>(body, hex)?;
+    // integers may only be turned into floats by the Java suffixes, like "1f" or "2D"
+    let java_suffix = suffix == Some(CSuffix::Float) || suffix == Some(CSuffix::Double);
+    if !is_float && !java_suffix {
+        return None;
+    }
+    Some((value, len, suffix))
+}
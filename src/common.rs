@@ -1,6 +1,9 @@
 use core::marker::PhantomData;
 use core::ptr;
 
+use crate::float::Float;
+use crate::range::RangeStatus;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct AsciiStr<'a> {
     ptr: *const u8,
@@ -119,11 +122,6 @@ pub trait ByteSlice: AsRef<[u8]> + AsMut<[u8]> {
         !self.as_ref().is_empty() && self.get_first() == c
     }
 
-    #[inline]
-    fn check_first2(&self, c1: u8, c2: u8) -> bool {
-        !self.as_ref().is_empty() && (self.get_first() == c1 || self.get_first() == c2)
-    }
-
     #[inline]
     fn eq_ignore_case(&self, u: &[u8]) -> bool {
         debug_assert!(self.as_ref().len() >= u.len());
@@ -136,24 +134,6 @@ pub trait ByteSlice: AsRef<[u8]> + AsMut<[u8]> {
         &self.as_ref()[n..]
     }
 
-    #[inline]
-    fn skip_chars(&self, c: u8) -> &[u8] {
-        let mut s = self.as_ref();
-        while s.check_first(c) {
-            s = s.advance(1);
-        }
-        s
-    }
-
-    #[inline]
-    fn skip_chars2(&self, c1: u8, c2: u8) -> &[u8] {
-        let mut s = self.as_ref();
-        while !s.is_empty() && (s.get_first() == c1 || s.get_first() == c2) {
-            s = s.advance(1);
-        }
-        s
-    }
-
     #[inline]
     fn read_u64(&self) -> u64 {
         debug_assert!(self.as_ref().len() >= 8);
@@ -205,6 +185,32 @@ impl AdjustedMantissa {
             power2,
         }
     }
+
+    #[inline]
+    pub fn range<F: Float>(self, nonzero: bool) -> RangeStatus {
+        if self.power2 == F::INFINITE_POWER {
+            RangeStatus::Overflow
+        } else if self.power2 != 0 {
+            RangeStatus::InRange
+        } else if self.mantissa != 0 {
+            RangeStatus::Subnormal
+        } else if nonzero {
+            // a zero result is only an underflow if the decimal itself isn't zero
+            RangeStatus::Underflow
+        } else {
+            RangeStatus::InRange
+        }
+    }
+
+    #[inline]
+    pub fn to_float<F: Float>(self, negative: bool) -> F {
+        let mut word = self.mantissa;
+        word |= (self.power2 as u64) << F::MANTISSA_EXPLICIT_BITS;
+        if negative {
+            word |= 1_u64 << F::SIGN_INDEX;
+        }
+        F::from_u64_bits(word)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,72 @@
+use core::fmt;
+use core::num::ParseFloatError;
+
+use crate::float::Float;
+use crate::options::ParseOptions;
+use crate::parse::parse_float;
+
+/// Error type of [`parse_core`](crate::parse_core()), with the same kinds and messages as
+/// `core::num::ParseFloatError` and convertible to and from it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CoreError {
+    /// The string is empty.
+    Empty,
+    /// The string is not a valid float literal.
+    Invalid,
+}
+
+impl CoreError {
+    fn as_str(self) -> &'static str {
+        // same messages as core::num::ParseFloatError
+        match self {
+            CoreError::Empty => "cannot parse float from empty string",
+            CoreError::Invalid => "invalid float literal",
+        }
+    }
+}
+
+impl fmt::Display for CoreError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CoreError {
+    fn description(&self) -> &'static str {
+        self.as_str()
+    }
+}
+
+impl From<CoreError> for ParseFloatError {
+    fn from(e: CoreError) -> Self {
+        // there's no other way to construct one
+        let s = match e {
+            CoreError::Empty => "",
+            CoreError::Invalid => "x",
+        };
+        s.parse::<f64>().unwrap_err()
+    }
+}
+
+impl From<ParseFloatError> for CoreError {
+    fn from(e: ParseFloatError) -> Self {
+        if e == ParseFloatError::from(CoreError::Empty) {
+            CoreError::Empty
+        } else {
+            CoreError::Invalid
+        }
+    }
+}
+
+#[inline]
+pub fn parse_core<F: Float>(s: &[u8]) -> Result<F, CoreError> {
+    // the default grammar is the one of core's dec2flt, only the error kinds differ
+    if s.is_empty() {
+        return Err(CoreError::Empty);
+    }
+    match parse_float(s, &ParseOptions::new()) {
+        Some((value, n)) if n == s.len() => Ok(value),
+        _ => Err(CoreError::Invalid),
+    }
+}
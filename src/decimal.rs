@@ -1,6 +1,8 @@
+use core::cmp::Ordering;
 use core::fmt::{self, Debug};
 
 use crate::common::{is_8digits, parse_digits, ByteSlice};
+use crate::options::ParseOptions;
 
 #[derive(Clone)]
 pub struct Decimal {
@@ -82,6 +84,36 @@ impl Decimal {
                 n += self.digits[i] as u64;
             }
         }
+        if self.round_up(dp) {
+            n += 1;
+        }
+        n
+    }
+
+    /// How the result of `round` compares to the exact value of the decimal.
+    #[inline]
+    pub fn rounding(&self) -> Ordering {
+        if self.num_digits == 0 {
+            return Ordering::Equal;
+        } else if self.decimal_point < 0 {
+            return Ordering::Less;
+        }
+        let dp = self.decimal_point as usize;
+        if self.round_up(dp) {
+            Ordering::Greater
+        } else if self.truncated
+            || self.digits[dp.min(self.num_digits)..self.num_digits]
+                .iter()
+                .any(|&d| d != 0)
+        {
+            Ordering::Less
+        } else {
+            Ordering::Equal
+        }
+    }
+
+    #[inline]
+    fn round_up(&self, dp: usize) -> bool {
         let mut round_up = false;
         if dp < self.num_digits {
             round_up = self.digits[dp] >= 5;
@@ -89,10 +121,7 @@ impl Decimal {
                 round_up = self.truncated || ((dp != 0) && (1 & self.digits[dp - 1] != 0))
             }
         }
-        if round_up {
-            n += 1;
-        }
-        n
+        round_up
     }
 
     #[inline]
@@ -187,8 +216,9 @@ impl Decimal {
 }
 
 #[inline]
-pub fn parse_decimal(mut s: &[u8]) -> Decimal {
-    // can't fail since it follows a call to parse_number
+pub fn parse_decimal(mut s: &[u8], options: &ParseOptions) -> Decimal {
+    // can't fail since it follows a call to parse_number, and the input is
+    // bounded by its length, so any separators found have already been validated
     let mut d = Decimal::default();
     let start = s;
     let c = s.get_first();
@@ -196,25 +226,41 @@ pub fn parse_decimal(mut s: &[u8]) -> Decimal {
     if c == b'-' || c == b'+' {
         s = s.advance(1);
     }
-    s = s.skip_chars(b'0');
-    parse_digits(&mut s, |digit| d.try_add_digit(digit));
-    if s.check_first(b'.') {
+    s = skip_zeros(s, options).0;
+    loop {
+        parse_digits(&mut s, |digit| d.try_add_digit(digit));
+        if s.is_empty() || !options.is_separator(s.get_first()) {
+            break;
+        }
+        s = s.advance(1);
+    }
+    if s.check_first(options.decimal_point) {
         s = s.advance(1);
         let first = s;
+        let mut n_separators = 0;
         if d.num_digits == 0 {
-            s = s.skip_chars(b'0');
+            let (rest, n) = skip_zeros(s, options);
+            s = rest;
+            n_separators += n;
         }
-        while s.len() >= 8 && d.num_digits + 8 < Decimal::MAX_DIGITS {
-            let v = s.read_u64();
-            if !is_8digits(v) {
+        loop {
+            while s.len() >= 8 && d.num_digits + 8 < Decimal::MAX_DIGITS {
+                let v = s.read_u64();
+                if !is_8digits(v) {
+                    break;
+                }
+                d.digits[d.num_digits..].write_u64(v - 0x3030_3030_3030_3030);
+                d.num_digits += 8;
+                s = s.advance(8);
+            }
+            parse_digits(&mut s, |digit| d.try_add_digit(digit));
+            if s.is_empty() || !options.is_separator(s.get_first()) {
                 break;
             }
-            d.digits[d.num_digits..].write_u64(v - 0x3030_3030_3030_3030);
-            d.num_digits += 8;
-            s = s.advance(8);
+            s = s.advance(1);
+            n_separators += 1;
         }
-        parse_digits(&mut s, |digit| d.try_add_digit(digit));
-        d.decimal_point = s.len() as i32 - first.len() as i32;
+        d.decimal_point = s.len() as i32 - first.len() as i32 + n_separators as i32;
     }
     if d.num_digits != 0 {
         // Ignore the trailing zeros if there are any
@@ -222,7 +268,7 @@ pub fn parse_decimal(mut s: &[u8]) -> Decimal {
         for &c in start[..(start.len() - s.len())].iter().rev() {
             if c == b'0' {
                 n_trailing_zeros += 1;
-            } else if c != b'.' {
+            } else if c != options.decimal_point && !options.is_separator(c) {
                 break;
             }
         }
@@ -234,8 +280,13 @@ pub fn parse_decimal(mut s: &[u8]) -> Decimal {
             d.num_digits = Decimal::MAX_DIGITS;
         }
     }
-    if s.check_first2(b'e', b'E') {
+    let has_exponent = if !s.is_empty() && options.is_exponent(s.get_first()) {
         s = s.advance(1);
+        true
+    } else {
+        options.implicit_exponent && (s.check_first(b'-') || s.check_first(b'+'))
+    };
+    if has_exponent {
         let mut neg_exp = false;
         if s.check_first(b'-') {
             neg_exp = true;
@@ -244,11 +295,17 @@ pub fn parse_decimal(mut s: &[u8]) -> Decimal {
             s = s.advance(1);
         }
         let mut exp_num = 0_i32;
-        parse_digits(&mut s, |digit| {
-            if exp_num < 0x10000 {
-                exp_num = 10 * exp_num + digit as i32;
+        loop {
+            parse_digits(&mut s, |digit| {
+                if exp_num < 0x10000 {
+                    exp_num = 10 * exp_num + digit as i32;
+                }
+            });
+            if s.is_empty() || !options.is_separator(s.get_first()) {
+                break;
             }
-        });
+            s = s.advance(1);
+        }
         d.decimal_point += if neg_exp { -exp_num } else { exp_num };
     }
     for i in d.num_digits..Decimal::MAX_DIGITS_WITHOUT_OVERFLOW {
@@ -257,6 +314,22 @@ pub fn parse_decimal(mut s: &[u8]) -> Decimal {
     d
 }
 
+#[inline]
+fn skip_zeros<'a>(mut s: &'a [u8], options: &ParseOptions) -> (&'a [u8], usize) {
+    // returns the remaining input along with the number of skipped separators
+    let mut n_separators = 0;
+    while !s.is_empty() {
+        let c = s.get_first();
+        if options.is_separator(c) {
+            n_separators += 1;
+        } else if c != b'0' {
+            break;
+        }
+        s = s.advance(1);
+    }
+    (s, n_separators)
+}
+
 #[inline]
 fn number_of_digits_decimal_left_shift(d: &Decimal, mut shift: usize) -> usize {
     const TABLE: [u16; 65] = [
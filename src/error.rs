@@ -0,0 +1,229 @@
+use core::fmt;
+
+use crate::float::Float;
+use crate::options::{ParseOptions, Whitespace};
+use crate::parse::{parse_float, parse_float_with_range};
+use crate::range::RangeStatus;
+use crate::whitespace::{decode, trim_start};
+use crate::Error;
+
+/// Detailed error type describing why and where a string is not a valid number.
+///
+/// Positions are byte offsets into the input. The offending character is decoded as UTF-8,
+/// or is `'\u{FFFD}'` if the input isn't valid UTF-8 at that position, and is `None` if
+/// the input ended where a digit was expected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseError {
+    /// The input is empty, or consists of whitespace only if whitespace is skipped.
+    Empty,
+    /// A sign which isn't allowed, like a second sign or a disabled leading `'+'`.
+    InvalidSign {
+        /// Offset of the sign.
+        position: usize,
+        /// The sign itself.
+        found: char,
+    },
+    /// The mantissa has no digits, like in `"abc"`, `"-"` or `"."`.
+    NoDigits {
+        /// Offset where a digit was expected.
+        position: usize,
+        /// The character found instead.
+        found: Option<char>,
+    },
+    /// The exponent marker isn't followed by digits, like in `"1e"` or `"1e+x"`.
+    InvalidExponent {
+        /// Offset where an exponent digit was expected.
+        position: usize,
+        /// The character found instead.
+        found: Option<char>,
+    },
+    /// The number is out of range and [`ParseOptions::allow_out_of_range`] is disabled.
+    OutOfRange {
+        /// Offset where the number starts.
+        position: usize,
+        /// How the number is out of range, never [`RangeStatus::InRange`].
+        range: RangeStatus,
+    },
+    /// A valid number is followed by characters which are not a part of it.
+    TrailingCharacters {
+        /// Number of bytes processed, which is the offset of the first unparsed character.
+        consumed: usize,
+        /// The first unparsed character.
+        found: char,
+    },
+}
+
+impl ParseError {
+    /// The byte offset of the error in the input (zero for empty inputs).
+    #[inline]
+    #[must_use]
+    pub fn position(&self) -> usize {
+        match *self {
+            ParseError::Empty => 0,
+            ParseError::InvalidSign { position, .. }
+            | ParseError::NoDigits { position, .. }
+            | ParseError::InvalidExponent { position, .. }
+            | ParseError::OutOfRange { position, .. } => position,
+            ParseError::TrailingCharacters { consumed, .. } => consumed,
+        }
+    }
+}
+
+struct Found(Option<char>);
+
+impl fmt::Display for Found {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0 {
+            Some(c) => fmt::Debug::fmt(&c, f),
+            None => f.write_str("end of input"),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    #[allow(clippy::uninlined_format_args)] // inlined arguments need Rust 1.58
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseError::Empty => write!(f, "cannot parse float from empty string"),
+            ParseError::InvalidSign { position, found } => {
+                write!(f, "invalid sign {:?} at position {}", found, position)
+            }
+            ParseError::NoDigits { position, found } => write!(
+                f,
+                "expected a digit at position {}, found {}",
+                position,
+                Found(found)
+            ),
+            ParseError::InvalidExponent { position, found } => write!(
+                f,
+                "expected an exponent digit at position {}, found {}",
+                position,
+                Found(found)
+            ),
+            ParseError::OutOfRange { position, range } => {
+                write!(f, "number at position {} {}", position, range)
+            }
+            ParseError::TrailingCharacters { consumed, found } => write!(
+                f,
+                "unexpected character {:?} after the number at position {}",
+                found, consumed
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {
+    fn description(&self) -> &'static str {
+        "error while parsing a float"
+    }
+}
+
+impl From<ParseError> for Error {
+    #[inline]
+    fn from(_: ParseError) -> Self {
+        Error
+    }
+}
+
+#[inline]
+fn char_at(s: &[u8], i: usize) -> char {
+    decode(&s[i..]).unwrap_or(core::char::REPLACEMENT_CHARACTER)
+}
+
+#[inline]
+fn found_at(s: &[u8], i: usize) -> Option<char> {
+    if i < s.len() {
+        Some(char_at(s, i))
+    } else {
+        None
+    }
+}
+
+#[inline]
+fn is_sign(s: &[u8], i: usize) -> bool {
+    s.get(i) == Some(&b'+') || s.get(i) == Some(&b'-')
+}
+
+// Only called once parsing has failed, so the success path doesn't pay for the details.
+#[cold]
+fn diagnose<F: Float>(s: &[u8], options: &ParseOptions, consumed: Option<usize>) -> ParseError {
+    let start = if options.whitespace == Whitespace::None {
+        0
+    } else {
+        s.len() - trim_start(s, |c| options.whitespace.is_space(c)).len()
+    };
+    if start == s.len() {
+        return ParseError::Empty;
+    }
+
+    if let Some(n) = consumed {
+        // the number itself is fine, but it's followed by something else
+        let after_mantissa = s[n - 1].is_ascii_digit()
+            || s[n - 1] == options.decimal_point
+            || options.is_separator(s[n - 1]);
+        let marker = options.is_exponent(s[n]);
+        if after_mantissa
+            && !s[start..n].iter().any(|&c| options.is_exponent(c))
+            && (marker || (options.implicit_exponent && is_sign(s, n)))
+        {
+            let mut i = n + marker as usize;
+            i += is_sign(s, i) as usize;
+            return ParseError::InvalidExponent {
+                position: i,
+                found: found_at(s, i),
+            };
+        }
+        return ParseError::TrailingCharacters {
+            consumed: n,
+            found: char_at(s, n),
+        };
+    }
+
+    if !options.out_of_range {
+        let options = options.allow_out_of_range(true);
+        if let Some((_, _, range)) = parse_float_with_range::<F>(s, &options) {
+            if range != RangeStatus::InRange {
+                return ParseError::OutOfRange {
+                    position: start,
+                    range,
+                };
+            }
+        }
+    }
+
+    let mut i = start;
+    if s[i] == b'-' || (s[i] == b'+' && options.leading_plus) {
+        i += 1;
+    }
+    if is_sign(s, i) {
+        return ParseError::InvalidSign {
+            position: i,
+            found: s[i] as char,
+        };
+    }
+    if options.leading_point && s.get(i) == Some(&options.decimal_point) {
+        i += 1;
+    }
+    ParseError::NoDigits {
+        position: i,
+        found: found_at(s, i),
+    }
+}
+
+#[inline]
+pub fn parse_detailed<F: Float>(s: &[u8], options: &ParseOptions) -> Result<F, ParseError> {
+    match parse_float(s, options) {
+        Some((value, n)) if n == s.len() => Ok(value),
+        Some((_, n)) => Err(diagnose::<F>(s, options, Some(n))),
+        None => Err(diagnose::<F>(s, options, None)),
+    }
+}
+
+#[inline]
+pub fn parse_partial_detailed<F: Float>(
+    s: &[u8],
+    options: &ParseOptions,
+) -> Result<(F, usize), ParseError> {
+    parse_float(s, options).ok_or_else(|| diagnose::<F>(s, options, None))
+}
@@ -0,0 +1,35 @@
+use core::cmp::Ordering;
+
+use crate::float::Float;
+use crate::options::ParseOptions;
+use crate::parse::parse_float_rounded;
+
+/// Whether a parsed number is exactly equal to the decimal in the string.
+///
+/// The direction of rounding refers to the value and not to the magnitude, so a negative
+/// number which overflows to negative infinity is rounded down.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Exactness {
+    /// The number is exactly equal to the decimal, or is a special value.
+    Exact,
+    /// The number is greater than the decimal.
+    RoundedUp,
+    /// The number is less than the decimal.
+    RoundedDown,
+}
+
+#[inline]
+pub fn parse_float_with_exactness<F: Float>(
+    s: &[u8],
+    options: &ParseOptions,
+) -> Option<(F, usize, Exactness)> {
+    let (value, n, _, ord): (F, _, _, _) = parse_float_rounded(s, options, true)?;
+    let negative = value.to_u64_bits() >> F::SIGN_INDEX != 0;
+    // the ordering is of the magnitudes, and it's always known when asked for
+    let exactness = match (ord.unwrap_or(Ordering::Equal), negative) {
+        (Ordering::Equal, _) => Exactness::Exact,
+        (Ordering::Greater, false) | (Ordering::Less, true) => Exactness::RoundedUp,
+        (Ordering::Less, false) | (Ordering::Greater, true) => Exactness::RoundedDown,
+    };
+    Some((value, n, exactness))
+}
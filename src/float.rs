@@ -42,7 +42,24 @@ pub trait Float:
 
     fn from_u64(v: u64) -> Self;
     fn from_u64_bits(v: u64) -> Self;
+    fn to_u64_bits(self) -> u64;
     fn pow10_fast_path(exponent: usize) -> Self;
+
+    /// Build a NaN with the given sign and quiet bit, and as much of the payload as fits.
+    #[inline]
+    fn nan_with_payload(negative: bool, quiet: bool, payload: u64) -> Self {
+        let quiet_bit = 1_u64 << (Self::MANTISSA_EXPLICIT_BITS - 1);
+        let mut mantissa = payload & (quiet_bit - 1);
+        if quiet {
+            mantissa |= quiet_bit;
+        } else if mantissa == 0 {
+            // a zero mantissa would be an infinity, so use the same bit as __builtin_nans("")
+            mantissa = quiet_bit >> 1;
+        }
+        let exponent = (Self::INFINITE_POWER as u64) << Self::MANTISSA_EXPLICIT_BITS;
+        let sign = (negative as u64) << Self::SIGN_INDEX;
+        Self::from_u64_bits(sign | exponent | mantissa)
+    }
 }
 
 impl private::Sealed for f32 {}
@@ -75,6 +92,11 @@ impl Float for f32 {
         f32::from_bits((v & 0xFFFFFFFF) as u32)
     }
 
+    #[inline]
+    fn to_u64_bits(self) -> u64 {
+        self.to_bits() as u64
+    }
+
     #[inline]
     fn pow10_fast_path(exponent: usize) -> Self {
         #[allow(clippy::use_self)]
@@ -115,6 +137,11 @@ impl Float for f64 {
         f64::from_bits(v)
     }
 
+    #[inline]
+    fn to_u64_bits(self) -> u64 {
+        self.to_bits()
+    }
+
     #[inline]
     fn pow10_fast_path(exponent: usize) -> Self {
         #[allow(clippy::use_self)]
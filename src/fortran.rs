@@ -0,0 +1,32 @@
+use crate::float::Float;
+use crate::options::{DigitSeparator, ParseOptions};
+use crate::parse::parse_float_scaled;
+use crate::whitespace::{trim_end, trim_start};
+
+// embedded blanks are ignored, as per the default BLANK='NULL' mode
+const FIELD_OPTIONS: ParseOptions =
+    ParseOptions::fortran().digit_separator(DigitSeparator::permissive(b' '));
+
+#[inline]
+pub fn parse_fortran_field<F: Float>(
+    s: &[u8],
+    width: usize,
+    decimals: usize,
+) -> Option<(F, usize)> {
+    // a short record is padded with blanks, so the field just ends early
+    let width = width.min(s.len());
+    let field = trim_end(trim_start(&s[..width], |c| c == ' '), |c| c == ' ');
+    if field.is_empty() {
+        return Some((F::default(), width));
+    }
+    // an explicit decimal point overrides the one implied by the edit descriptor
+    let scale = if field.contains(&b'.') {
+        0
+    } else {
+        -(decimals.min(0x7FFF_FFFF) as i64)
+    };
+    match parse_float_scaled(field, &FIELD_OPTIONS, scale) {
+        Some((value, n, _)) if n == field.len() => Some((value, width)),
+        _ => None,
+    }
+}
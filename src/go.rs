@@ -0,0 +1,131 @@
+use core::fmt;
+
+use crate::float::Float;
+use crate::hex::parse_hex_literal;
+use crate::options::{DigitSeparator, ParseOptions};
+use crate::parse::parse_float;
+
+/// Error type of [`parse_go`](crate::parse_go()), distinguishing syntax errors from range
+/// errors the same way Go's `strconv.ParseFloat` does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GoError<T> {
+    /// The string is not a valid number, like Go's `strconv.ErrSyntax`.
+    Syntax,
+    /// The number is too large in magnitude, like Go's `strconv.ErrRange`. Holds the
+    /// value Go returns along with the error, which is an infinity of the same sign.
+    Range(T),
+}
+
+impl<T> fmt::Display for GoError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GoError::Syntax => write!(f, "invalid syntax"),
+            GoError::Range(_) => write!(f, "value out of range"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: fmt::Debug> std::error::Error for GoError<T> {
+    fn description(&self) -> &'static str {
+        match self {
+            GoError::Syntax => "invalid syntax",
+            GoError::Range(_) => "value out of range",
+        }
+    }
+}
+
+const GO_OPTIONS: ParseOptions = ParseOptions::new()
+    .allow_special_values(false)
+    .digit_separator(DigitSeparator::permissive(b'_'));
+
+#[inline]
+fn is_hex_prefix(s: &[u8]) -> bool {
+    s.len() >= 2 && s[0] == b'0' && (s[1] == b'x' || s[1] == b'X')
+}
+
+#[inline]
+fn strip_sign(s: &[u8]) -> &[u8] {
+    match s.first() {
+        Some(&c) if c == b'+' || c == b'-' => &s[1..],
+        _ => s,
+    }
+}
+
+/// Port of Go's `underscoreOK`: an underscore must be preceded by a digit or the base
+/// prefix, and followed by a digit.
+#[inline]
+fn underscores_ok(s: &[u8]) -> bool {
+    let s = strip_sign(s);
+    let hex = is_hex_prefix(s);
+    // b'^' at the start, b'0' after a digit or the prefix, b'_' after an underscore
+    let (mut saw, start) = if hex { (b'0', 2) } else { (b'^', 0) };
+    for &c in &s[start..] {
+        if c.is_ascii_digit() || (hex && c.is_ascii_hexdigit()) {
+            saw = b'0';
+        } else if c == b'_' {
+            if saw != b'0' {
+                return false;
+            }
+            saw = b'_';
+        } else if saw == b'_' {
+            return false;
+        } else {
+            saw = b'!';
+        }
+    }
+    saw != b'_'
+}
+
+#[inline]
+fn parse_special<F: Float>(s: &[u8]) -> Option<F> {
+    // "inf" and "infinity" with an optional sign, or "nan" without it, in any case
+    if s.eq_ignore_ascii_case(b"nan") {
+        return Some(F::NAN);
+    }
+    let (negative, rest) = match s.first() {
+        Some(b'+') => (false, &s[1..]),
+        Some(b'-') => (true, &s[1..]),
+        _ => (false, s),
+    };
+    if rest.eq_ignore_ascii_case(b"inf") || rest.eq_ignore_ascii_case(b"infinity") {
+        Some(if negative {
+            F::NEG_INFINITY
+        } else {
+            F::INFINITY
+        })
+    } else {
+        None
+    }
+}
+
+#[inline]
+fn parse_go_number<F: Float>(s: &[u8]) -> Option<F> {
+    let unsigned = strip_sign(s);
+    if s.contains(&b'_') && !underscores_ok(s) {
+        return None;
+    }
+    if is_hex_prefix(unsigned) {
+        let num = parse_hex_literal(s, b'_')?;
+        return Some(num.to_adjusted_mantissa::<F>().to_float(num.negative));
+    }
+    match parse_float(s, &GO_OPTIONS) {
+        Some((value, n)) if n == s.len() => Some(value),
+        _ => None,
+    }
+}
+
+#[inline]
+pub fn parse_go<F: Float>(s: &[u8]) -> Result<F, GoError<F>> {
+    if let Some(value) = parse_special(s) {
+        return Ok(value);
+    }
+    match parse_go_number::<F>(s) {
+        // like Go, only overflow is a range error, underflow to zero isn't
+        Some(value) if value == F::INFINITY || value == F::NEG_INFINITY => {
+            Err(GoError::Range(value))
+        }
+        Some(value) => Ok(value),
+        None => Err(GoError::Syntax),
+    }
+}
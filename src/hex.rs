@@ -0,0 +1,211 @@
+use crate::common::{AdjustedMantissa, AsciiStr};
+use crate::float::Float;
+
+#[inline]
+fn hex_digit(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HexNumber {
+    pub exponent: i64,
+    pub mantissa: u64,
+    pub negative: bool,
+    pub truncated: bool,
+}
+
+impl HexNumber {
+    /// Append a digit of a power-of-two radix, `bits` being the number of bits per digit.
+    #[inline]
+    pub fn push_digit(&mut self, digit: u8, bits: u32, fractional: bool) {
+        if self.mantissa >> (64 - bits) == 0 {
+            self.mantissa = (self.mantissa << bits) | digit as u64;
+            if fractional {
+                self.exponent -= bits as i64;
+            }
+        } else {
+            // the mantissa is full, only keep track of whether anything was dropped
+            self.truncated |= digit != 0;
+            if !fractional {
+                self.exponent += bits as i64;
+            }
+        }
+    }
+
+    /// Round the number `mantissa * 2^exponent` to the nearest float, ties to even.
+    #[inline]
+    pub fn to_adjusted_mantissa<F: Float>(self) -> AdjustedMantissa {
+        let am_zero = AdjustedMantissa::zero_pow2(0);
+        let am_inf = AdjustedMantissa::zero_pow2(F::INFINITE_POWER);
+
+        if self.mantissa == 0 {
+            return am_zero;
+        }
+        let lz = self.mantissa.leading_zeros();
+        let w = self.mantissa << lz;
+        let exponent = self.exponent - lz as i64 + 63; // w is now in [2^63, 2^64)
+        if exponent > (F::INFINITE_POWER + F::MINIMUM_EXPONENT) as i64 {
+            return am_inf;
+        }
+
+        // the number of low bits to be dropped, larger for subnormals
+        let mut power2 = exponent - F::MINIMUM_EXPONENT as i64;
+        let mut shift = 63 - F::MANTISSA_EXPLICIT_BITS as i64;
+        if power2 <= 0 {
+            shift += 1 - power2;
+            power2 = 0;
+        }
+        if shift >= 64 {
+            // only possibly rounds up to the smallest subnormal if above the halfway point
+            let above_half = shift == 64 && (w != 1 << 63 || self.truncated);
+            return AdjustedMantissa {
+                mantissa: above_half as u64,
+                power2: 0,
+            };
+        }
+
+        let mut mantissa = w >> shift;
+        let rest = w & ((1_u64 << shift) - 1);
+        let half = 1_u64 << (shift - 1);
+        if rest > half || (rest == half && (self.truncated || mantissa & 1 == 1)) {
+            mantissa += 1;
+        }
+        if power2 == 0 {
+            // subnormal, might have been rounded up to the smallest normal
+            power2 = (mantissa >> F::MANTISSA_EXPLICIT_BITS) as i64;
+        } else if mantissa >> (F::MANTISSA_EXPLICIT_BITS + 1) != 0 {
+            mantissa >>= 1;
+            power2 += 1;
+        }
+        if power2 >= F::INFINITE_POWER as i64 {
+            return am_inf;
+        }
+        mantissa &= !(1_u64 << F::MANTISSA_EXPLICIT_BITS);
+        AdjustedMantissa {
+            mantissa,
+            power2: power2 as i32,
+        }
+    }
+}
+
+#[inline]
+fn parse_hex_digits(s: &mut AsciiStr<'_>, separator: Option<u8>, mut func: impl FnMut(u8)) {
+    while !s.is_empty() {
+        let c = s.first();
+        match hex_digit(c) {
+            Some(digit) => func(digit),
+            None if Some(c) == separator => {}
+            None => break,
+        }
+        s.step();
+    }
+}
+
+#[inline]
+fn parse_binary_exponent(s: &mut AsciiStr<'_>, separator: Option<u8>) -> i64 {
+    // the first character is 'p'/'P'
+    let start = *s;
+    s.step();
+    let mut exp_num = 0_i64;
+    let mut neg_exp = false;
+    if !s.is_empty() && s.first_either(b'-', b'+') {
+        neg_exp = s.first_is(b'-');
+        s.step();
+    }
+    if s.check_first_digit() {
+        loop {
+            s.parse_digits(|digit| {
+                if exp_num < 0x10000 {
+                    exp_num = 10 * exp_num + digit as i64; // no overflows here
+                }
+            });
+            match separator {
+                Some(c) if s.check_first(c) => {}
+                _ => break,
+            }
+            s.step();
+        }
+        if neg_exp {
+            -exp_num
+        } else {
+            exp_num
+        }
+    } else {
+        *s = start; // ignore 'p' and return back
+        0
+    }
+}
+
+/// Parse a hexadecimal number, skipping any separator bytes after the prefix without
+/// checking where they are placed; that's left to the caller.
+#[inline]
+pub fn parse_hex_number(s: &[u8], separator: Option<u8>) -> Option<(HexNumber, usize)> {
+    let mut s = AsciiStr::new(s);
+    let start = s;
+    let mut num = HexNumber::default();
+
+    // handle optional +/- sign
+    if s.check_first_either(b'-', b'+') {
+        num.negative = s.first_is(b'-');
+        s.step();
+    }
+
+    // handle the mandatory 0x/0X prefix
+    if !s.check_first(b'0') {
+        return None;
+    }
+    s.step();
+    if !s.check_first_either(b'x', b'X') {
+        return None;
+    }
+    s.step();
+
+    // parse hex digits before and after the dot
+    let mut n_digits = 0;
+    parse_hex_digits(&mut s, separator, |digit| {
+        num.push_digit(digit, 4, false);
+        n_digits += 1;
+    });
+    if s.check_first(b'.') {
+        s.step();
+        parse_hex_digits(&mut s, separator, |digit| {
+            num.push_digit(digit, 4, true);
+            n_digits += 1;
+        });
+    }
+    if n_digits == 0 {
+        return None;
+    }
+
+    // handle binary exponent
+    if s.check_first_either(b'p', b'P') {
+        num.exponent += parse_binary_exponent(&mut s, separator);
+    }
+
+    Some((num, s.offset_from(&start) as _))
+}
+
+/// Parse a whole hexadecimal float literal like in C or Go, where the binary exponent
+/// is mandatory.
+#[inline]
+pub fn parse_hex_literal(s: &[u8], separator: u8) -> Option<HexNumber> {
+    // the binary exponent is mandatory, and 'p' can't be a hex digit
+    let (num, n) = parse_hex_number(s, Some(separator))?;
+    if n == s.len() && s.iter().any(|&c| c == b'p' || c == b'P') {
+        Some(num)
+    } else {
+        None
+    }
+}
+
+#[inline]
+pub fn parse_hex_float<F: Float>(s: &[u8]) -> Option<(F, usize)> {
+    let (num, rest) = parse_hex_number(s, None)?;
+    let am = num.to_adjusted_mantissa::<F>();
+    Some((am.to_float(num.negative), rest))
+}
@@ -0,0 +1,181 @@
+use core::ops::Range;
+
+use crate::float::Float;
+use crate::number::parse_number;
+use crate::options::{ParseOptions, Whitespace};
+use crate::parse::parse_float;
+use crate::whitespace::trim_start;
+
+/// Description of how a parsed number is written, like its parts and their positions.
+///
+/// Ranges are byte offsets into the input, including the leading whitespace if it's skipped.
+/// Digit separators count as a part of the digits they separate. For special values like
+/// `"inf"` or `"nan"` all of the ranges are empty.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct NumberInfo {
+    pub(crate) negative: bool,
+    pub(crate) special: bool,
+    pub(crate) significant_digits: usize,
+    pub(crate) has_point: bool,
+    pub(crate) has_exponent: bool,
+    pub(crate) exponent: i64,
+    pub(crate) integer: Range<usize>,
+    pub(crate) fraction: Range<usize>,
+    pub(crate) exponent_digits: Range<usize>,
+}
+
+impl NumberInfo {
+    /// Whether the number has a minus sign, which is the case for `"-0"` too.
+    #[inline]
+    #[must_use]
+    pub const fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Whether the number is a special value like an infinity, a NaN or a custom token.
+    #[inline]
+    #[must_use]
+    pub const fn is_special(&self) -> bool {
+        self.special
+    }
+
+    /// Number of digits from the first nonzero digit to the last one, so that `"0.0120"`
+    /// has three of them and zero has none.
+    #[inline]
+    #[must_use]
+    pub const fn significant_digits(&self) -> usize {
+        self.significant_digits
+    }
+
+    /// Whether the number has a decimal point, even if there are no digits after it.
+    #[inline]
+    #[must_use]
+    pub const fn has_decimal_point(&self) -> bool {
+        self.has_point
+    }
+
+    /// Whether the number has an exponent, either with a marker like `'e'` or implicit.
+    #[inline]
+    #[must_use]
+    pub const fn has_exponent(&self) -> bool {
+        self.has_exponent
+    }
+
+    /// Value of the explicit exponent, saturated to the range of `i64`, or zero if there's
+    /// no exponent.
+    #[inline]
+    #[must_use]
+    pub const fn exponent(&self) -> i64 {
+        self.exponent
+    }
+
+    /// Digits of the integer part, which is empty for numbers like `".5"`.
+    #[inline]
+    #[must_use]
+    pub fn integer_range(&self) -> Range<usize> {
+        self.integer.clone()
+    }
+
+    /// Digits of the fractional part after the decimal point, empty if there are none.
+    #[inline]
+    #[must_use]
+    pub fn fraction_range(&self) -> Range<usize> {
+        self.fraction.clone()
+    }
+
+    /// The exponent with its sign but without the marker, empty if there's no exponent.
+    #[inline]
+    #[must_use]
+    pub fn exponent_range(&self) -> Range<usize> {
+        self.exponent_digits.clone()
+    }
+}
+
+#[inline]
+fn parse_exponent(s: &[u8]) -> i64 {
+    let (negative, digits) = match s.first() {
+        Some(&b'-') => (true, &s[1..]),
+        Some(&b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+    let mut exp = 0_i64;
+    for &c in digits.iter().filter(|c| c.is_ascii_digit()) {
+        let digit = i64::from(c - b'0');
+        exp = exp.saturating_mul(10);
+        exp = if negative {
+            exp.saturating_sub(digit)
+        } else {
+            exp.saturating_add(digit)
+        };
+    }
+    exp
+}
+
+#[inline]
+fn describe(s: &[u8], start: usize, options: &ParseOptions, negative: bool) -> NumberInfo {
+    let mut info = NumberInfo {
+        negative,
+        integer: start..start,
+        fraction: start..start,
+        exponent_digits: start..start,
+        ..NumberInfo::default()
+    };
+    let end = match parse_number(&s[start..], options) {
+        // custom special tokens may start like a number, as in "1.#INF"
+        Some((_, n))
+            if trim_start(&s[start + n..], |c| options.whitespace.is_space(c)).is_empty() =>
+        {
+            start + n
+        }
+        _ => {
+            info.special = true;
+            return info;
+        }
+    };
+
+    // the number has already been validated, so it's enough to look at the characters
+    let is_digit = |c: u8| c.is_ascii_digit() || options.is_separator(c);
+    let digits_end = |i: usize| i + s[i..end].iter().take_while(|&&c| is_digit(c)).count();
+    let mut i = start;
+    if s[i] == b'-' || s[i] == b'+' {
+        i += 1;
+    }
+    info.integer = i..digits_end(i);
+    i = info.integer.end;
+    info.fraction = i..i;
+    if i < end && s[i] == options.decimal_point {
+        info.has_point = true;
+        info.fraction = i + 1..digits_end(i + 1);
+        i = info.fraction.end;
+    }
+    info.exponent_digits = end..end;
+    if i < end {
+        info.has_exponent = true;
+        i += options.is_exponent(s[i]) as usize;
+        info.exponent_digits = i..end;
+        info.exponent = parse_exponent(&s[i..end]);
+    }
+
+    let digits = s[info.integer.clone()]
+        .iter()
+        .chain(&s[info.fraction.clone()])
+        .filter(|c| c.is_ascii_digit());
+    info.significant_digits = digits.skip_while(|&&c| c == b'0').count();
+    info
+}
+
+#[inline]
+pub fn parse_float_with_info<F: Float>(
+    s: &[u8],
+    options: &ParseOptions,
+) -> Option<(F, usize, NumberInfo)> {
+    let (value, n): (F, _) = parse_float(s, options)?;
+    let start = if options.whitespace == Whitespace::None {
+        0
+    } else {
+        s.len() - trim_start(s, |c| options.whitespace.is_space(c)).len()
+    };
+    let negative = value.to_u64_bits() >> F::SIGN_INDEX != 0;
+    Some((value, n, describe(&s[..n], start, options, negative)))
+}
@@ -0,0 +1,58 @@
+use crate::float::Float;
+use crate::options::ParseOptions;
+use crate::parse::parse_float;
+use crate::radix::parse_radix_float;
+use crate::whitespace::{trim_end, trim_start};
+
+// StrUnsignedDecimalLiteral, except "Infinity" which is case-sensitive
+const JS_OPTIONS: ParseOptions = ParseOptions::new().allow_special_values(false);
+
+#[inline]
+fn is_js_space(c: char) -> bool {
+    // WhiteSpace and LineTerminator: Unicode White_Space except U+0085, plus the BOM
+    (c.is_whitespace() && c != '\u{85}') || c == '\u{feff}'
+}
+
+#[inline]
+fn non_decimal_radix(s: &[u8]) -> Option<u32> {
+    match s.get(..2)? {
+        b"0x" | b"0X" => Some(16),
+        b"0o" | b"0O" => Some(8),
+        b"0b" | b"0B" => Some(2),
+        _ => None,
+    }
+}
+
+#[inline]
+pub fn parse_js<F: Float>(s: &[u8]) -> F {
+    let s = trim_end(trim_start(s, is_js_space), is_js_space);
+    if s.is_empty() {
+        return F::default();
+    }
+
+    // NonDecimalIntegerLiteral: no sign, no fraction and no separators
+    if let Some(radix) = non_decimal_radix(s) {
+        let digits = &s[2..];
+        if digits.is_empty() || !digits.iter().all(|&c| (c as char).is_digit(radix)) {
+            return F::NAN;
+        }
+        return parse_radix_float(digits, radix).map_or(F::NAN, |(value, _)| value);
+    }
+
+    let (negative, rest) = match s[0] {
+        b'+' => (false, &s[1..]),
+        b'-' => (true, &s[1..]),
+        _ => (false, s),
+    };
+    if rest == b"Infinity" {
+        return if negative {
+            F::NEG_INFINITY
+        } else {
+            F::INFINITY
+        };
+    }
+    match parse_float(s, &JS_OPTIONS) {
+        Some((value, n)) if n == s.len() => value,
+        _ => F::NAN,
+    }
+}
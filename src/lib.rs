@@ -9,11 +9,43 @@
 //! - [`parse`](crate::parse()) treats the whole string as a decimal number and returns an
 //!   error if there are invalid characters or if the string is empty.
 //! - [`parse_partial`](crate::parse_partial()) tries to find the longest substring at the
-//! beginning of the given input string that can be parsed as a decimal number and,
-//! in the case of success, returns the parsed value along the number of characters processed;
-//! an error is returned if the string doesn't start with a decimal number or if it is empty.
-//! This function is most useful as a building block when constructing more complex parsers,
-//! or when parsing streams of data.
+//!   beginning of the given input string that can be parsed as a decimal number and,
+//!   in the case of success, returns the parsed value along the number of characters processed;
+//!   an error is returned if the string doesn't start with a decimal number or if it is empty.
+//!   This function is most useful as a building block when constructing more complex parsers,
+//!   or when parsing streams of data.
+//!
+//! Both functions have counterparts accepting [`ParseOptions`](crate::ParseOptions) which allow
+//! to configure the number grammar – the decimal point, the exponent characters, the sign,
+//! the special values and the surrounding whitespace:
+//! [`parse_with_options`](crate::parse_with_options()) and
+//! [`parse_partial_with_options`](crate::parse_partial_with_options()). All of these
+//! functions return an opaque [`Error`](crate::Error); the variants with the `_detailed`
+//! suffix like [`parse_detailed`](crate::parse_detailed()) return a
+//! [`ParseError`](crate::ParseError) instead, which tells what went wrong and where.
+//! Similarly, [`parse_with_exactness`](crate::parse_with_exactness()) also tells whether
+//! the value is exactly equal to the decimal or was rounded up or down, and
+//! [`parse_with_info`](crate::parse_with_info()) describes how the number is written.
+//!
+//! Hexadecimal floating-point literals like `"0x1.8p3"` can be parsed via
+//! [`parse_hex`](crate::parse_hex()) and [`parse_hex_partial`](crate::parse_hex_partial()),
+//! and numbers in any radix from 2 to 36 via [`parse_radix`](crate::parse_radix()) and
+//! [`parse_radix_partial`](crate::parse_radix_partial()). Rust float literals like
+//! `"1_000.5f32"` can be lexed via [`parse_rust_literal`](crate::parse_rust_literal()) and
+//! [`parse_rust_literal_partial`](crate::parse_rust_literal_partial()), and C, C++ or Java
+//! float literals like `"1'000.5f"` via [`parse_c_literal`](crate::parse_c_literal()) and
+//! [`parse_c_literal_partial`](crate::parse_c_literal_partial()). Finally,
+//! [`parse_strtod`](crate::parse_strtod()) mimics the behaviour of C's `strtod`,
+//! [`parse_python`](crate::parse_python()) follows the ASCII subset of Python's `float()`,
+//! [`parse_js`](crate::parse_js()) implements JavaScript's `Number()`,
+//! [`parse_go`](crate::parse_go()) mirrors Go's `strconv.ParseFloat`,
+//! [`parse_core`](crate::parse_core()) is a drop-in replacement for `str::parse::<f64>()`
+//! as of Rust 1.55,
+//! [`parse_toml`](crate::parse_toml()) follows the grammar of TOML floats,
+//! [`parse_yaml`](crate::parse_yaml()) follows the YAML 1.2 core schema,
+//! [`parse_xsd`](crate::parse_xsd()) follows the lexical space of XML Schema's `xsd:double`
+//! and [`parse_fortran_field`](crate::parse_fortran_field()) reads fixed-width fields
+//! of Fortran records like `Fw.d` edit descriptors do.
 //!
 //! ## Examples
 //!
@@ -29,6 +61,11 @@
 //! assert_eq!(x, 0.0123);
 //! assert_eq!(n, 8);
 //! assert_eq!(&s[n..], "foo");
+//!
+//! // Parse a number with a custom grammar.
+//! let options = fast_float::ParseOptions::new().decimal_point(b',');
+//! let x: f64 = fast_float::parse_with_options("1,5", &options).unwrap();
+//! assert_eq!(x, 1.5);
 //! ```
 
 #![warn(clippy::all, clippy::pedantic, clippy::nursery, clippy::cargo)]
@@ -46,16 +83,49 @@
 
 use core::fmt::{self, Display};
 
+mod bigint;
 mod binary;
+mod c_literal;
 mod common;
+mod core_compat;
 mod decimal;
+mod error;
+mod exact;
 mod float;
+mod fortran;
+mod go;
+mod hex;
+mod info;
+mod js;
+mod literal;
 mod number;
+mod options;
 mod parse;
+mod python;
+mod radix;
+mod range;
 mod simple;
+mod strtod;
 mod table;
+mod toml;
+mod whitespace;
+mod xsd;
+mod yaml;
+
+pub use c_literal::CSuffix;
+pub use core_compat::CoreError;
+pub use error::ParseError;
+pub use exact::Exactness;
+pub use go::GoError;
+pub use info::NumberInfo;
+pub use literal::RustSuffix;
+pub use options::{DigitSeparator, ParseOptions, SpecialTokens, SpecialValue, Whitespace};
+pub use range::RangeStatus;
 
 /// Opaque error type for fast-float parsing functions.
+///
+/// See [`ParseError`] for the error type of the functions which report what went wrong
+/// and where, and which converts into this one.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Error;
 
@@ -85,14 +155,39 @@ pub trait FastFloat: float::Float {
     /// or if any characters are left remaining unparsed.
     #[inline]
     fn parse_float<S: AsRef<[u8]>>(s: S) -> Result<Self> {
+        Self::parse_float_with_options(s, &ParseOptions::new())
+    }
+
+    /// Parse a decimal number from string into float (partial).
+    ///
+    /// This method parses as many characters as possible and returns the resulting number along
+    /// with the number of digits processed (in case of success, this number is always positive).
+    ///
+    /// # Errors
+    ///
+    /// Will return an error either if the string doesn't start with a valid decimal number
+    /// – that is, if no zero digits were processed.
+    #[inline]
+    fn parse_float_partial<S: AsRef<[u8]>>(s: S) -> Result<(Self, usize)> {
+        Self::parse_float_partial_with_options(s, &ParseOptions::new())
+    }
+
+    /// Parse a decimal number from string into float using custom grammar options (full).
+    ///
+    /// # Errors
+    ///
+    /// Will return an error either if the string is not a valid decimal number
+    /// according to the given options or if any characters are left remaining unparsed.
+    #[inline]
+    fn parse_float_with_options<S: AsRef<[u8]>>(s: S, options: &ParseOptions) -> Result<Self> {
         let s = s.as_ref();
-        match Self::parse_float_partial(s) {
+        match Self::parse_float_partial_with_options(s, options) {
             Ok((v, n)) if n == s.len() => Ok(v),
             _ => Err(Error),
         }
     }
 
-    /// Parse a decimal number from string into float (partial).
+    /// Parse a decimal number from string into float using custom grammar options (partial).
     ///
     /// This method parses as many characters as possible and returns the resulting number along
     /// with the number of digits processed (in case of success, this number is always positive).
@@ -100,10 +195,366 @@ pub trait FastFloat: float::Float {
     /// # Errors
     ///
     /// Will return an error either if the string doesn't start with a valid decimal number
-    /// – that is, if no zero digits were processed.
+    /// according to the given options – that is, if no zero digits were processed.
     #[inline]
-    fn parse_float_partial<S: AsRef<[u8]>>(s: S) -> Result<(Self, usize)> {
-        parse::parse_float(s.as_ref()).ok_or(Error)
+    fn parse_float_partial_with_options<S: AsRef<[u8]>>(
+        s: S,
+        options: &ParseOptions,
+    ) -> Result<(Self, usize)> {
+        parse::parse_float(s.as_ref(), options).ok_or(Error)
+    }
+
+    /// Parse a decimal number from string into float using custom grammar options (full),
+    /// reporting the kind and the position of the error if it fails.
+    ///
+    /// # Errors
+    ///
+    /// Will return a [`ParseError`] describing why the string is not a valid decimal number
+    /// according to the given options, including if any characters are left unparsed.
+    #[inline]
+    fn parse_float_detailed_with_options<S: AsRef<[u8]>>(
+        s: S,
+        options: &ParseOptions,
+    ) -> core::result::Result<Self, ParseError> {
+        error::parse_detailed(s.as_ref(), options)
+    }
+
+    /// Parse a decimal number from string into float using custom grammar options (partial),
+    /// reporting the kind and the position of the error if it fails.
+    ///
+    /// # Errors
+    ///
+    /// Will return a [`ParseError`] describing why the string doesn't start with a valid
+    /// decimal number according to the given options.
+    #[inline]
+    fn parse_float_partial_detailed_with_options<S: AsRef<[u8]>>(
+        s: S,
+        options: &ParseOptions,
+    ) -> core::result::Result<(Self, usize), ParseError> {
+        error::parse_partial_detailed(s.as_ref(), options)
+    }
+
+    /// Parse a decimal number from string into float using custom grammar options (full),
+    /// reporting whether it overflowed, underflowed or became subnormal.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error either if the string is not a valid decimal number
+    /// according to the given options or if any characters are left remaining unparsed.
+    #[inline]
+    fn parse_float_with_range<S: AsRef<[u8]>>(
+        s: S,
+        options: &ParseOptions,
+    ) -> Result<(Self, RangeStatus)> {
+        let s = s.as_ref();
+        match Self::parse_float_partial_with_range(s, options) {
+            Ok((v, n, range)) if n == s.len() => Ok((v, range)),
+            _ => Err(Error),
+        }
+    }
+
+    /// Parse a decimal number from string into float using custom grammar options (partial),
+    /// reporting whether it overflowed, underflowed or became subnormal.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error either if the string doesn't start with a valid decimal number
+    /// according to the given options – that is, if no zero digits were processed.
+    #[inline]
+    fn parse_float_partial_with_range<S: AsRef<[u8]>>(
+        s: S,
+        options: &ParseOptions,
+    ) -> Result<(Self, usize, RangeStatus)> {
+        parse::parse_float_with_range(s.as_ref(), options).ok_or(Error)
+    }
+
+    /// Parse a decimal number from string into float using custom grammar options (full),
+    /// reporting whether it's exactly equal to the decimal or was rounded up or down.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error either if the string is not a valid decimal number
+    /// according to the given options or if any characters are left remaining unparsed.
+    #[inline]
+    fn parse_float_with_exactness<S: AsRef<[u8]>>(
+        s: S,
+        options: &ParseOptions,
+    ) -> Result<(Self, Exactness)> {
+        let s = s.as_ref();
+        match Self::parse_float_partial_with_exactness(s, options) {
+            Ok((v, n, exactness)) if n == s.len() => Ok((v, exactness)),
+            _ => Err(Error),
+        }
+    }
+
+    /// Parse a decimal number from string into float using custom grammar options (partial),
+    /// reporting whether it's exactly equal to the decimal or was rounded up or down.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error either if the string doesn't start with a valid decimal number
+    /// according to the given options – that is, if no zero digits were processed.
+    #[inline]
+    fn parse_float_partial_with_exactness<S: AsRef<[u8]>>(
+        s: S,
+        options: &ParseOptions,
+    ) -> Result<(Self, usize, Exactness)> {
+        exact::parse_float_with_exactness(s.as_ref(), options).ok_or(Error)
+    }
+
+    /// Parse a decimal number from string into float using custom grammar options (full),
+    /// along with the description of how the number is written.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error either if the string is not a valid decimal number
+    /// according to the given options or if any characters are left remaining unparsed.
+    #[inline]
+    fn parse_float_with_info<S: AsRef<[u8]>>(
+        s: S,
+        options: &ParseOptions,
+    ) -> Result<(Self, NumberInfo)> {
+        let s = s.as_ref();
+        match Self::parse_float_partial_with_info(s, options) {
+            Ok((v, n, info)) if n == s.len() => Ok((v, info)),
+            _ => Err(Error),
+        }
+    }
+
+    /// Parse a decimal number from string into float using custom grammar options (partial),
+    /// along with the description of how the number is written.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error either if the string doesn't start with a valid decimal number
+    /// according to the given options – that is, if no zero digits were processed.
+    #[inline]
+    fn parse_float_partial_with_info<S: AsRef<[u8]>>(
+        s: S,
+        options: &ParseOptions,
+    ) -> Result<(Self, usize, NumberInfo)> {
+        info::parse_float_with_info(s.as_ref(), options).ok_or(Error)
+    }
+
+    /// Parse a hexadecimal floating-point literal like `"0x1.8p3"` into float (full).
+    ///
+    /// # Errors
+    ///
+    /// Will return an error either if the string is not a valid hexadecimal float
+    /// or if any characters are left remaining unparsed.
+    #[inline]
+    fn parse_hex_float<S: AsRef<[u8]>>(s: S) -> Result<Self> {
+        let s = s.as_ref();
+        match Self::parse_hex_float_partial(s) {
+            Ok((v, n)) if n == s.len() => Ok(v),
+            _ => Err(Error),
+        }
+    }
+
+    /// Parse a hexadecimal floating-point literal like `"0x1.8p3"` into float (partial).
+    ///
+    /// This method parses as many characters as possible and returns the resulting number along
+    /// with the number of characters processed (in case of success, this number is always positive).
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the string doesn't start with a valid hexadecimal float.
+    #[inline]
+    fn parse_hex_float_partial<S: AsRef<[u8]>>(s: S) -> Result<(Self, usize)> {
+        hex::parse_hex_float(s.as_ref()).ok_or(Error)
+    }
+
+    /// Parse a number in the given radix like `"101.011"` into float (full).
+    ///
+    /// # Errors
+    ///
+    /// Will return an error either if the string is not a valid number in the given radix
+    /// or if any characters are left remaining unparsed.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `radix` is not in the range from 2 to 36.
+    #[inline]
+    fn parse_float_radix<S: AsRef<[u8]>>(s: S, radix: u32) -> Result<Self> {
+        let s = s.as_ref();
+        match Self::parse_float_radix_partial(s, radix) {
+            Ok((v, n)) if n == s.len() => Ok(v),
+            _ => Err(Error),
+        }
+    }
+
+    /// Parse a number in the given radix like `"101.011"` into float (partial).
+    ///
+    /// This method parses as many characters as possible and returns the resulting number along
+    /// with the number of characters processed (in case of success, this number is always positive).
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the string doesn't start with a valid number in the given radix.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `radix` is not in the range from 2 to 36.
+    #[inline]
+    fn parse_float_radix_partial<S: AsRef<[u8]>>(s: S, radix: u32) -> Result<(Self, usize)> {
+        radix::parse_radix_float(s.as_ref(), radix).ok_or(Error)
+    }
+
+    /// Parse a Rust float literal like `"1_000.5e-3f32"` into float (full).
+    ///
+    /// # Errors
+    ///
+    /// Will return an error either if the string is not a valid Rust float literal
+    /// or if any characters are left remaining unparsed.
+    #[inline]
+    fn parse_rust_literal<S: AsRef<[u8]>>(s: S) -> Result<(Self, Option<RustSuffix>)> {
+        let s = s.as_ref();
+        match Self::parse_rust_literal_partial(s) {
+            Ok((v, n, suffix)) if n == s.len() => Ok((v, suffix)),
+            _ => Err(Error),
+        }
+    }
+
+    /// Parse a Rust float literal like `"1_000.5e-3f32"` into float (partial).
+    ///
+    /// This method parses as many characters as possible and returns the resulting number along
+    /// with the number of characters processed including the suffix, and the suffix itself.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the string doesn't start with a valid Rust float literal.
+    #[inline]
+    fn parse_rust_literal_partial<S: AsRef<[u8]>>(
+        s: S,
+    ) -> Result<(Self, usize, Option<RustSuffix>)> {
+        literal::parse_rust_literal(s.as_ref()).ok_or(Error)
+    }
+
+    /// Parse a C, C++ or Java float literal like `"1'000.5e-3f"` into float (full).
+    ///
+    /// # Errors
+    ///
+    /// Will return an error either if the string is not a valid float literal
+    /// or if any characters are left remaining unparsed.
+    #[inline]
+    fn parse_c_literal<S: AsRef<[u8]>>(s: S) -> Result<(Self, Option<CSuffix>)> {
+        let s = s.as_ref();
+        match Self::parse_c_literal_partial(s) {
+            Ok((v, n, suffix)) if n == s.len() => Ok((v, suffix)),
+            _ => Err(Error),
+        }
+    }
+
+    /// Parse a C, C++ or Java float literal like `"1'000.5e-3f"` into float (partial).
+    ///
+    /// This method parses as many characters as possible and returns the resulting number along
+    /// with the number of characters processed including the suffix, and the suffix itself.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the string doesn't start with a valid float literal.
+    #[inline]
+    fn parse_c_literal_partial<S: AsRef<[u8]>>(s: S) -> Result<(Self, usize, Option<CSuffix>)> {
+        c_literal::parse_c_literal(s.as_ref()).ok_or(Error)
+    }
+
+    /// Parse a number from string into float the same way C's `strtod` does.
+    ///
+    /// Returns the resulting number along with the number of characters processed,
+    /// which is zero (along with a zero value) if the string doesn't start with a number.
+    #[inline]
+    fn parse_float_strtod<S: AsRef<[u8]>>(s: S) -> (Self, usize) {
+        strtod::parse_strtod(s.as_ref())
+    }
+
+    /// Parse a number from string into float following the ASCII subset of the grammar
+    /// of Python's `float()`.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the string is not accepted by Python's `float()`
+    /// or contains non-ASCII digits.
+    #[inline]
+    fn parse_float_python<S: AsRef<[u8]>>(s: S) -> Result<Self> {
+        python::parse_python(s.as_ref()).ok_or(Error)
+    }
+
+    /// Parse a TOML 1.0 float from string into float.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the string is not a valid TOML float.
+    #[inline]
+    fn parse_float_toml<S: AsRef<[u8]>>(s: S) -> Result<Self> {
+        toml::parse_toml(s.as_ref()).ok_or(Error)
+    }
+
+    /// Parse a float as defined by the YAML 1.2 core schema from string into float.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the string doesn't match the core schema float regex.
+    #[inline]
+    fn parse_float_yaml<S: AsRef<[u8]>>(s: S) -> Result<Self> {
+        yaml::parse_yaml(s.as_ref()).ok_or(Error)
+    }
+
+    /// Parse a number from string into float the same way JavaScript's `Number()` does.
+    ///
+    /// Never fails: invalid strings produce NaN.
+    #[inline]
+    fn parse_float_js<S: AsRef<[u8]>>(s: S) -> Self {
+        js::parse_js(s.as_ref())
+    }
+
+    /// Parse a number in the lexical space of XML Schema's `xsd:double` and `xsd:float`.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the string is not in the lexical space of the type.
+    #[inline]
+    fn parse_float_xsd<S: AsRef<[u8]>>(s: S) -> Result<Self> {
+        xsd::parse_xsd(s.as_ref()).ok_or(Error)
+    }
+
+    /// Parse a number from string into float the same way Go's `strconv.ParseFloat` does.
+    ///
+    /// # Errors
+    ///
+    /// Will return [`GoError::Syntax`] if the string is not accepted by Go, or
+    /// [`GoError::Range`] along with an infinity if the number overflows.
+    #[inline]
+    fn parse_float_go<S: AsRef<[u8]>>(s: S) -> core::result::Result<Self, GoError<Self>> {
+        go::parse_go(s.as_ref())
+    }
+
+    /// Parse a number from string into float exactly like `str::parse()` from `core` does
+    /// since Rust 1.55.
+    ///
+    /// # Errors
+    ///
+    /// Will return [`CoreError::Empty`] if the string is empty, or [`CoreError::Invalid`]
+    /// if it is not a valid float literal.
+    #[inline]
+    fn parse_float_core<S: AsRef<[u8]>>(s: S) -> core::result::Result<Self, CoreError> {
+        core_compat::parse_core(s.as_ref())
+    }
+
+    /// Parse a Fortran `Fw.d` field from the start of a fixed-width record.
+    ///
+    /// Returns the resulting number along with the number of characters processed, which is
+    /// the field width unless the record is shorter.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the field is not a valid Fortran real number.
+    #[inline]
+    fn parse_fortran_field<S: AsRef<[u8]>>(
+        s: S,
+        width: usize,
+        decimals: usize,
+    ) -> Result<(Self, usize)> {
+        fortran::parse_fortran_field(s.as_ref(), width, decimals).ok_or(Error)
     }
 }
 
@@ -134,3 +585,672 @@ pub fn parse<T: FastFloat, S: AsRef<[u8]>>(s: S) -> Result<T> {
 pub fn parse_partial<T: FastFloat, S: AsRef<[u8]>>(s: S) -> Result<(T, usize)> {
     T::parse_float_partial(s)
 }
+
+/// Parse a decimal number from string into float using custom grammar options (full).
+///
+/// # Errors
+///
+/// Will return an error either if the string is not a valid decimal number
+/// according to the given options or if any characters are left remaining unparsed.
+#[inline]
+pub fn parse_with_options<T: FastFloat, S: AsRef<[u8]>>(s: S, options: &ParseOptions) -> Result<T> {
+    T::parse_float_with_options(s, options)
+}
+
+/// Parse a decimal number from string into float using custom grammar options (partial).
+///
+/// This function parses as many characters as possible and returns the resulting number along
+/// with the number of digits processed (in case of success, this number is always positive).
+///
+/// # Errors
+///
+/// Will return an error either if the string doesn't start with a valid decimal number
+/// according to the given options – that is, if no zero digits were processed.
+#[inline]
+pub fn parse_partial_with_options<T: FastFloat, S: AsRef<[u8]>>(
+    s: S,
+    options: &ParseOptions,
+) -> Result<(T, usize)> {
+    T::parse_float_partial_with_options(s, options)
+}
+
+/// Parse a decimal number from string into float (full), reporting the kind and the
+/// position of the error if it fails.
+///
+/// This accepts the same strings as [`parse`](crate::parse()) and is just as fast when
+/// parsing succeeds, since the error is only analyzed after a failure:
+///
+/// ```rust
+/// use fast_float::ParseError;
+///
+/// assert_eq!(fast_float::parse_detailed::<f64, _>("1.5"), Ok(1.5));
+/// assert_eq!(fast_float::parse_detailed::<f64, _>(""), Err(ParseError::Empty));
+/// let e = fast_float::parse_detailed::<f64, _>("1.5e+x").unwrap_err();
+/// assert_eq!(e, ParseError::InvalidExponent { position: 5, found: Some('x') });
+/// let e = fast_float::parse_detailed::<f64, _>("12.5kg").unwrap_err();
+/// assert_eq!(e, ParseError::TrailingCharacters { consumed: 4, found: 'k' });
+/// assert_eq!(e.to_string(), "unexpected character 'k' after the number at position 4");
+/// let _: fast_float::Error = e.into(); // the opaque error type
+/// ```
+///
+/// # Errors
+///
+/// Will return a [`ParseError`] describing why the string is not a valid decimal number,
+/// including if any characters are left unparsed.
+#[inline]
+pub fn parse_detailed<T: FastFloat, S: AsRef<[u8]>>(s: S) -> core::result::Result<T, ParseError> {
+    T::parse_float_detailed_with_options(s, &ParseOptions::new())
+}
+
+/// Parse a decimal number from string into float (partial), reporting the kind and the
+/// position of the error if it fails.
+///
+/// ```rust
+/// use fast_float::ParseError;
+///
+/// assert_eq!(fast_float::parse_partial_detailed::<f64, _>("1.5e+x"), Ok((1.5, 3)));
+/// let e = fast_float::parse_partial_detailed::<f64, _>("-x").unwrap_err();
+/// assert_eq!(e, ParseError::NoDigits { position: 1, found: Some('x') });
+/// assert_eq!(e.to_string(), "expected a digit at position 1, found 'x'");
+/// ```
+///
+/// # Errors
+///
+/// Will return a [`ParseError`] describing why the string doesn't start with a valid
+/// decimal number.
+#[inline]
+pub fn parse_partial_detailed<T: FastFloat, S: AsRef<[u8]>>(
+    s: S,
+) -> core::result::Result<(T, usize), ParseError> {
+    T::parse_float_partial_detailed_with_options(s, &ParseOptions::new())
+}
+
+/// Parse a decimal number from string into float using custom grammar options (full),
+/// reporting the kind and the position of the error if it fails.
+///
+/// # Errors
+///
+/// Will return a [`ParseError`] describing why the string is not a valid decimal number
+/// according to the given options, including if any characters are left unparsed.
+#[inline]
+pub fn parse_detailed_with_options<T: FastFloat, S: AsRef<[u8]>>(
+    s: S,
+    options: &ParseOptions,
+) -> core::result::Result<T, ParseError> {
+    T::parse_float_detailed_with_options(s, options)
+}
+
+/// Parse a decimal number from string into float using custom grammar options (partial),
+/// reporting the kind and the position of the error if it fails.
+///
+/// # Errors
+///
+/// Will return a [`ParseError`] describing why the string doesn't start with a valid
+/// decimal number according to the given options.
+#[inline]
+pub fn parse_partial_detailed_with_options<T: FastFloat, S: AsRef<[u8]>>(
+    s: S,
+    options: &ParseOptions,
+) -> core::result::Result<(T, usize), ParseError> {
+    T::parse_float_partial_detailed_with_options(s, options)
+}
+
+/// Parse a decimal number from string into float using custom grammar options (full),
+/// reporting whether it overflowed, underflowed or became subnormal.
+///
+/// The value is the same as the one returned by
+/// [`parse_with_options`](crate::parse_with_options()), so out-of-range numbers are still
+/// accepted unless [`ParseOptions::allow_out_of_range`] is disabled:
+///
+/// ```rust
+/// use fast_float::{ParseOptions, RangeStatus};
+///
+/// let options = ParseOptions::new();
+/// let parse = |s| fast_float::parse_with_range::<f64, _>(s, &options).unwrap();
+/// assert_eq!(parse("1.5"), (1.5, RangeStatus::InRange));
+/// assert_eq!(parse("-1e400"), (std::f64::NEG_INFINITY, RangeStatus::Overflow));
+/// assert_eq!(parse("1e-400"), (0., RangeStatus::Underflow));
+/// assert_eq!(parse("5e-324"), (5e-324, RangeStatus::Subnormal));
+/// assert_eq!(parse("0e-400"), (0., RangeStatus::InRange));
+/// assert_eq!(parse("inf"), (std::f64::INFINITY, RangeStatus::InRange));
+/// ```
+///
+/// # Errors
+///
+/// Will return an error either if the string is not a valid decimal number
+/// according to the given options or if any characters are left remaining unparsed.
+#[inline]
+pub fn parse_with_range<T: FastFloat, S: AsRef<[u8]>>(
+    s: S,
+    options: &ParseOptions,
+) -> Result<(T, RangeStatus)> {
+    T::parse_float_with_range(s, options)
+}
+
+/// Parse a decimal number from string into float using custom grammar options (partial),
+/// reporting whether it overflowed, underflowed or became subnormal.
+///
+/// # Errors
+///
+/// Will return an error either if the string doesn't start with a valid decimal number
+/// according to the given options – that is, if no zero digits were processed.
+#[inline]
+pub fn parse_partial_with_range<T: FastFloat, S: AsRef<[u8]>>(
+    s: S,
+    options: &ParseOptions,
+) -> Result<(T, usize, RangeStatus)> {
+    T::parse_float_partial_with_range(s, options)
+}
+
+/// Parse a decimal number from string into float using custom grammar options (full),
+/// reporting whether it's exactly equal to the decimal or was rounded up or down.
+///
+/// The value is the same as the one returned by
+/// [`parse_with_options`](crate::parse_with_options()); the exactness comes from the
+/// rounding done while parsing, and only the inputs which are too close to a representable
+/// value or a halfway point for the fast algorithms have to take the slow path.
+///
+/// ```rust
+/// use fast_float::{Exactness, ParseOptions};
+///
+/// let options = ParseOptions::new();
+/// let parse = |s| fast_float::parse_with_exactness::<f64, _>(s, &options).unwrap().1;
+/// assert_eq!(parse("0.375"), Exactness::Exact);
+/// assert_eq!(parse("0.1"), Exactness::RoundedUp);
+/// assert_eq!(parse("-0.1"), Exactness::RoundedDown);
+/// assert_eq!(parse("0.3"), Exactness::RoundedDown);
+/// assert_eq!(parse("9007199254740993"), Exactness::RoundedDown);
+/// assert_eq!(parse("1e400"), Exactness::RoundedUp);
+/// assert_eq!(parse("inf"), Exactness::Exact);
+/// ```
+///
+/// # Errors
+///
+/// Will return an error either if the string is not a valid decimal number
+/// according to the given options or if any characters are left remaining unparsed.
+#[inline]
+pub fn parse_with_exactness<T: FastFloat, S: AsRef<[u8]>>(
+    s: S,
+    options: &ParseOptions,
+) -> Result<(T, Exactness)> {
+    T::parse_float_with_exactness(s, options)
+}
+
+/// Parse a decimal number from string into float using custom grammar options (partial),
+/// reporting whether it's exactly equal to the decimal or was rounded up or down.
+///
+/// # Errors
+///
+/// Will return an error either if the string doesn't start with a valid decimal number
+/// according to the given options – that is, if no zero digits were processed.
+#[inline]
+pub fn parse_partial_with_exactness<T: FastFloat, S: AsRef<[u8]>>(
+    s: S,
+    options: &ParseOptions,
+) -> Result<(T, usize, Exactness)> {
+    T::parse_float_partial_with_exactness(s, options)
+}
+
+/// Parse a decimal number from string into float using custom grammar options (full),
+/// along with the description of how the number is written.
+///
+/// The [`NumberInfo`](crate::NumberInfo) tells the sign, the number of significant digits,
+/// whether there's a decimal point or an exponent and where the integer, the fractional
+/// and the exponent parts are, which is useful to infer the type of a column of numbers.
+///
+/// ```rust
+/// use fast_float::ParseOptions;
+///
+/// let options = ParseOptions::new();
+/// let (x, info) = fast_float::parse_with_info::<f64, _>("-12.50e+3", &options).unwrap();
+/// assert_eq!(x, -12500.);
+/// assert!(info.is_negative() && !info.is_special());
+/// assert_eq!(info.significant_digits(), 4);
+/// assert!(info.has_decimal_point() && info.has_exponent());
+/// assert_eq!(info.exponent(), 3);
+/// assert_eq!(info.integer_range(), 1..3);
+/// assert_eq!(info.fraction_range(), 4..6);
+/// assert_eq!(info.exponent_range(), 7..9);
+///
+/// let (_, info) = fast_float::parse_with_info::<f64, _>("inf", &options).unwrap();
+/// assert!(info.is_special());
+/// ```
+///
+/// # Errors
+///
+/// Will return an error either if the string is not a valid decimal number
+/// according to the given options or if any characters are left remaining unparsed.
+#[inline]
+pub fn parse_with_info<T: FastFloat, S: AsRef<[u8]>>(
+    s: S,
+    options: &ParseOptions,
+) -> Result<(T, NumberInfo)> {
+    T::parse_float_with_info(s, options)
+}
+
+/// Parse a decimal number from string into float using custom grammar options (partial),
+/// along with the description of how the number is written.
+///
+/// # Errors
+///
+/// Will return an error either if the string doesn't start with a valid decimal number
+/// according to the given options – that is, if no zero digits were processed.
+#[inline]
+pub fn parse_partial_with_info<T: FastFloat, S: AsRef<[u8]>>(
+    s: S,
+    options: &ParseOptions,
+) -> Result<(T, usize, NumberInfo)> {
+    T::parse_float_partial_with_info(s, options)
+}
+
+/// Parse a hexadecimal floating-point literal like `"0x1.8p3"` into float (full).
+///
+/// The accepted format is the one produced by C's `printf("%a")`: an optional sign,
+/// a mandatory `0x` or `0X` prefix, hexadecimal digits with an optional fractional part,
+/// and an optional binary exponent introduced by `p` or `P`. The result is rounded to
+/// the nearest float, ties to even.
+///
+/// # Errors
+///
+/// Will return an error either if the string is not a valid hexadecimal float
+/// or if any characters are left remaining unparsed.
+#[inline]
+pub fn parse_hex<T: FastFloat, S: AsRef<[u8]>>(s: S) -> Result<T> {
+    T::parse_hex_float(s)
+}
+
+/// Parse a hexadecimal floating-point literal like `"0x1.8p3"` into float (partial).
+///
+/// This function parses as many characters as possible and returns the resulting number along
+/// with the number of characters processed (in case of success, this number is always positive).
+///
+/// # Errors
+///
+/// Will return an error if the string doesn't start with a valid hexadecimal float.
+#[inline]
+pub fn parse_hex_partial<T: FastFloat, S: AsRef<[u8]>>(s: S) -> Result<(T, usize)> {
+    T::parse_hex_float_partial(s)
+}
+
+/// Parse a number in the given radix like `"101.011"` or `"z.z"` into float (full).
+///
+/// The accepted format is an optional sign followed by digits with an optional fractional
+/// part separated by `'.'`; digits above 9 are represented by the letters `a` to `z` in any
+/// case, like in [`u64::from_str_radix`]. There is no exponent part and no special values.
+/// The result is rounded to the nearest float, ties to even, for every radix.
+///
+/// ```rust
+/// let x: f64 = fast_float::parse_radix("101.011", 2).unwrap();
+/// assert_eq!(x, 5.375);
+/// let x: f64 = fast_float::parse_radix("-Z.I", 36).unwrap();
+/// assert_eq!(x, -35.5);
+/// ```
+///
+/// # Errors
+///
+/// Will return an error either if the string is not a valid number in the given radix
+/// or if any characters are left remaining unparsed.
+///
+/// # Panics
+///
+/// Will panic if `radix` is not in the range from 2 to 36.
+#[inline]
+pub fn parse_radix<T: FastFloat, S: AsRef<[u8]>>(s: S, radix: u32) -> Result<T> {
+    T::parse_float_radix(s, radix)
+}
+
+/// Parse a number in the given radix like `"101.011"` or `"z.z"` into float (partial).
+///
+/// This function parses as many characters as possible and returns the resulting number along
+/// with the number of characters processed (in case of success, this number is always positive).
+///
+/// # Errors
+///
+/// Will return an error if the string doesn't start with a valid number in the given radix.
+///
+/// # Panics
+///
+/// Will panic if `radix` is not in the range from 2 to 36.
+#[inline]
+pub fn parse_radix_partial<T: FastFloat, S: AsRef<[u8]>>(s: S, radix: u32) -> Result<(T, usize)> {
+    T::parse_float_radix_partial(s, radix)
+}
+
+/// Parse a Rust float literal like `"1_000.5e-3f32"` into float (full).
+///
+/// The literal is lexed the same way `rustc` does it: there is no sign, the integer part is
+/// mandatory, `'_'` separators are accepted anywhere after the first digit including the
+/// exponent, and there are no special values. Integer literals like `"1"` are only accepted
+/// with a float suffix. The `f32` or `f64` suffix is returned along with the value, which is
+/// always parsed as `T` regardless of the suffix.
+///
+/// ```rust
+/// use fast_float::RustSuffix;
+///
+/// let (x, suffix) = fast_float::parse_rust_literal::<f64, _>("1_000.5e1_0").unwrap();
+/// assert_eq!((x, suffix), (1_000.5e1_0, None));
+/// let (x, suffix) = fast_float::parse_rust_literal::<f32, _>("1f32").unwrap();
+/// assert_eq!((x, suffix), (1., Some(RustSuffix::F32)));
+/// assert!(fast_float::parse_rust_literal::<f64, _>("1").is_err());
+/// ```
+///
+/// # Errors
+///
+/// Will return an error either if the string is not a valid Rust float literal
+/// or if any characters are left remaining unparsed.
+#[inline]
+pub fn parse_rust_literal<T: FastFloat, S: AsRef<[u8]>>(s: S) -> Result<(T, Option<RustSuffix>)> {
+    T::parse_rust_literal(s)
+}
+
+/// Parse a Rust float literal like `"1_000.5e-3f32"` into float (partial).
+///
+/// This function parses as many characters as possible and returns the resulting number along
+/// with the number of characters processed including the suffix, and the suffix itself.
+///
+/// Like in `rustc`, a dot followed by another dot, an underscore or an identifier is not
+/// a part of the literal, so `"1..2"` or `"1.max(2)"` do not start with a float literal;
+/// on the other hand, invalid suffixes and exponents without digits are errors.
+///
+/// # Errors
+///
+/// Will return an error if the string doesn't start with a valid Rust float literal.
+#[inline]
+pub fn parse_rust_literal_partial<T: FastFloat, S: AsRef<[u8]>>(
+    s: S,
+) -> Result<(T, usize, Option<RustSuffix>)> {
+    T::parse_rust_literal_partial(s)
+}
+
+/// Parse a C, C++ or Java float literal like `"1'000.5e-3f"` into float (full).
+///
+/// Both decimal literals like `"1.5e3"`, `"1."` or `".5"` and hexadecimal ones like
+/// `"0x1.8p3"` (where the binary exponent is mandatory) are accepted, with C++14 digit
+/// separators like `"1'000.5"` between two digits. There is no sign and there are no special
+/// values. The suffix is returned along with the value, which is always parsed as `T`
+/// regardless of the suffix. Integer literals like `"1"` are only accepted with the Java
+/// suffixes `f` and `d`, as in `"1f"`.
+///
+/// ```rust
+/// use fast_float::CSuffix;
+///
+/// let (x, suffix) = fast_float::parse_c_literal::<f64, _>("1'000.5e-1").unwrap();
+/// assert_eq!((x, suffix), (100.05, None));
+/// let (x, suffix) = fast_float::parse_c_literal::<f32, _>("0x1.8p1f").unwrap();
+/// assert_eq!((x, suffix), (3., Some(CSuffix::Float)));
+/// let (x, suffix) = fast_float::parse_c_literal::<f32, _>(".5bf16").unwrap();
+/// assert_eq!((x, suffix), (0.5, Some(CSuffix::BFloat16)));
+/// assert!(fast_float::parse_c_literal::<f64, _>("1").is_err());
+/// assert!(fast_float::parse_c_literal::<f64, _>("0x1.8").is_err());
+/// ```
+///
+/// # Errors
+///
+/// Will return an error either if the string is not a valid float literal
+/// or if any characters are left remaining unparsed.
+#[inline]
+pub fn parse_c_literal<T: FastFloat, S: AsRef<[u8]>>(s: S) -> Result<(T, Option<CSuffix>)> {
+    T::parse_c_literal(s)
+}
+
+/// Parse a C, C++ or Java float literal like `"1'000.5e-3f"` into float (partial).
+///
+/// This function parses as many characters as possible and returns the resulting number along
+/// with the number of characters processed including the suffix, and the suffix itself.
+///
+/// The extent of the literal is that of a preprocessing number in C and C++, which also
+/// swallows things like invalid suffixes, `"1.5.0"` or `"1.5_km"`; those are errors rather
+/// than shorter literals followed by something else.
+///
+/// ```rust
+/// let (x, n, _) = fast_float::parse_c_literal_partial::<f64, _>("2.5e+3f*x").unwrap();
+/// assert_eq!((x, n), (2500., 7));
+/// assert!(fast_float::parse_c_literal_partial::<f64, _>("1.5.0").is_err());
+/// ```
+///
+/// # Errors
+///
+/// Will return an error if the string doesn't start with a valid float literal.
+#[inline]
+pub fn parse_c_literal_partial<T: FastFloat, S: AsRef<[u8]>>(
+    s: S,
+) -> Result<(T, usize, Option<CSuffix>)> {
+    T::parse_c_literal_partial(s)
+}
+
+/// Parse a number from string into float the same way C's `strtod` does.
+///
+/// Leading whitespace as defined by `isspace()` in the "C" locale is skipped, both decimal
+/// and hexadecimal numbers like `"0x1.8p3"` are accepted, as well as `inf`, `infinity` and
+/// `nan` in any case; `nan` may be followed by an `"(n-char-sequence)"` which is consumed,
+/// and which sets the NaN payload if it's an integer as read by `strtoull` with base 0.
+///
+/// Like `strtod`, this function never fails: it returns the resulting number along with
+/// the number of characters processed including the leading whitespace, which corresponds
+/// to the end pointer. If the string doesn't start with a number, zero is returned as both
+/// the value and the number of characters processed.
+///
+/// ```rust
+/// assert_eq!(fast_float::parse_strtod::<f64, _>("  0x1.8p3 "), (12., 9));
+/// assert_eq!(fast_float::parse_strtod::<f64, _>("0xg"), (0., 1));
+/// assert_eq!(fast_float::parse_strtod::<f64, _>("foo"), (0., 0));
+/// let (x, n) = fast_float::parse_strtod::<f64, _>("-nan(123)");
+/// assert_eq!((x.to_bits(), n), (0xfff8_0000_0000_007b, 9));
+/// ```
+#[inline]
+pub fn parse_strtod<T: FastFloat, S: AsRef<[u8]>>(s: S) -> (T, usize) {
+    T::parse_float_strtod(s)
+}
+
+/// Parse a number from string into float following the ASCII subset of the grammar
+/// of Python's `float()`.
+///
+/// Surrounding Unicode whitespace (the same characters `float()` strips) is ignored, `'_'` is
+/// accepted between two digits anywhere in the number including the exponent, and
+/// `inf`, `infinity` and `nan` are accepted in any case with an optional sign.
+/// Hexadecimal numbers are not accepted. Unlike `float()`, which also accepts any Unicode
+/// decimal digits like in `"١٢"`, only ASCII digits are supported.
+///
+/// ```rust
+/// let x: f64 = fast_float::parse_python("  -1_000.5e-1\n").unwrap();
+/// assert_eq!(x, -100.05);
+/// assert!(fast_float::parse_python::<f64, _>("1__0").is_err());
+/// assert!(fast_float::parse_python::<f64, _>("0x1p0").is_err());
+/// ```
+///
+/// # Errors
+///
+/// Will return an error if the string is not accepted by Python's `float()`
+/// or contains non-ASCII digits.
+#[inline]
+pub fn parse_python<T: FastFloat, S: AsRef<[u8]>>(s: S) -> Result<T> {
+    T::parse_float_python(s)
+}
+
+/// Parse a TOML 1.0 float from string into float.
+///
+/// Both the integer and the fractional parts are required around the `'.'`, and either a
+/// fraction or an exponent must be present (otherwise it's a TOML integer). Leading zeros
+/// aren't allowed in the integer part, `'_'` is only accepted between two digits, and the
+/// special values are `inf` and `nan` in lowercase with an optional sign.
+///
+/// ```rust
+/// let x: f64 = fast_float::parse_toml("-1_000.5e-1").unwrap();
+/// assert_eq!(x, -100.05);
+/// assert!(fast_float::parse_toml::<f64, _>("-inf").unwrap().is_infinite());
+/// assert!(fast_float::parse_toml::<f64, _>("Infinity").is_err());
+/// assert!(fast_float::parse_toml::<f64, _>("01.5").is_err());
+/// assert!(fast_float::parse_toml::<f64, _>("42").is_err());
+/// ```
+///
+/// # Errors
+///
+/// Will return an error if the string is not a valid TOML float.
+#[inline]
+pub fn parse_toml<T: FastFloat, S: AsRef<[u8]>>(s: S) -> Result<T> {
+    T::parse_float_toml(s)
+}
+
+/// Parse a float as defined by the YAML 1.2 core schema from string into float.
+///
+/// The string must match `[-+]? ( \. [0-9]+ | [0-9]+ ( \. [0-9]* )? ) ( [eE] [-+]? [0-9]+ )?`
+/// (which also matches integers, those are resolved before floats in the core schema),
+/// or be one of the special values: `.inf`, `.Inf` or `.INF` with an optional sign, or
+/// `.nan`, `.NaN` or `.NAN` without a sign.
+///
+/// ```rust
+/// let x: f64 = fast_float::parse_yaml("-.5e3").unwrap();
+/// assert_eq!(x, -500.);
+/// assert_eq!(fast_float::parse_yaml::<f64, _>("-.Inf").unwrap(), std::f64::NEG_INFINITY);
+/// assert!(fast_float::parse_yaml::<f64, _>(".NaN").unwrap().is_nan());
+/// assert!(fast_float::parse_yaml::<f64, _>("inf").is_err());
+/// assert!(fast_float::parse_yaml::<f64, _>(".iNf").is_err());
+/// ```
+///
+/// # Errors
+///
+/// Will return an error if the string doesn't match the core schema float regex.
+#[inline]
+pub fn parse_yaml<T: FastFloat, S: AsRef<[u8]>>(s: S) -> Result<T> {
+    T::parse_float_yaml(s)
+}
+
+/// Parse a number from string into float the same way JavaScript's `Number()` does.
+///
+/// This is `StringToNumber` from the ECMAScript specification: surrounding whitespace and
+/// line terminators are ignored, an empty string is zero, `Infinity` is case-sensitive
+/// (there's no `inf` or `NaN`), and unsigned `0x`, `0o` and `0b` integers are accepted.
+/// Any other string is NaN.
+///
+/// ```rust
+/// assert_eq!(fast_float::parse_js::<f64, _>(" -1.5e3\n"), -1500.);
+/// assert_eq!(fast_float::parse_js::<f64, _>("0x1F"), 31.);
+/// assert_eq!(fast_float::parse_js::<f64, _>(""), 0.);
+/// assert!(fast_float::parse_js::<f64, _>("-Infinity").is_infinite());
+/// assert!(fast_float::parse_js::<f64, _>("inf").is_nan());
+/// assert!(fast_float::parse_js::<f64, _>("-0x1F").is_nan());
+/// ```
+#[inline]
+pub fn parse_js<T: FastFloat, S: AsRef<[u8]>>(s: S) -> T {
+    T::parse_float_js(s)
+}
+
+/// Parse a number in the lexical space of XML Schema's `xsd:double` and `xsd:float`.
+///
+/// This follows XML Schema 1.1: the special values are exactly `INF`, `+INF`, `-INF` and
+/// `NaN` (case-sensitive, no `infinity`), numbers like `"1."` and `".5"` are accepted and
+/// so are leading and trailing XML whitespace characters as per `whiteSpace="collapse"`.
+///
+/// ```rust
+/// let x: f64 = fast_float::parse_xsd(" -1.5E3\n").unwrap();
+/// assert_eq!(x, -1500.);
+/// assert!(fast_float::parse_xsd::<f32, _>("+INF").unwrap().is_infinite());
+/// assert!(fast_float::parse_xsd::<f64, _>("NaN").unwrap().is_nan());
+/// assert!(fast_float::parse_xsd::<f64, _>("inf").is_err());
+/// assert!(fast_float::parse_xsd::<f64, _>("-NaN").is_err());
+/// ```
+///
+/// # Errors
+///
+/// Will return an error if the string is not in the lexical space of the type.
+#[inline]
+pub fn parse_xsd<T: FastFloat, S: AsRef<[u8]>>(s: S) -> Result<T> {
+    T::parse_float_xsd(s)
+}
+
+/// Parse a number from string into float the same way Go's `strconv.ParseFloat` does.
+///
+/// Both decimal and hexadecimal numbers are accepted as per the Go syntax for floating-point
+/// literals, the latter requiring a `'p'` exponent. Underscores may separate digits, or
+/// follow the `0x` prefix. The special values `inf`, `infinity` (with an optional sign) and
+/// `nan` are matched case-insensitively. There are no surrounding spaces.
+///
+/// ```rust
+/// use fast_float::GoError;
+///
+/// let x: f64 = fast_float::parse_go("0x_1.8p1").unwrap();
+/// assert_eq!(x, 3.);
+/// assert_eq!(fast_float::parse_go::<f64, _>("-1_000.5").unwrap(), -1000.5);
+/// assert_eq!(fast_float::parse_go::<f64, _>("0x1.8"), Err(GoError::Syntax));
+/// assert_eq!(
+///     fast_float::parse_go::<f64, _>("-1e400"),
+///     Err(GoError::Range(std::f64::NEG_INFINITY))
+/// );
+/// assert_eq!(fast_float::parse_go::<f64, _>("1e-400"), Ok(0.));
+/// ```
+///
+/// # Errors
+///
+/// Will return [`GoError::Syntax`] if the string is not accepted by Go, or
+/// [`GoError::Range`] along with an infinity if the number overflows. Like in Go,
+/// underflow to zero is not considered a range error.
+#[inline]
+pub fn parse_go<T: FastFloat, S: AsRef<[u8]>>(s: S) -> core::result::Result<T, GoError<T>> {
+    T::parse_float_go(s)
+}
+
+/// Parse a number from string into float exactly like `str::parse()` from `core` does
+/// since Rust 1.55.
+///
+/// This accepts the same strings (which is also the grammar of [`parse`](crate::parse())),
+/// produces the same values, and reports the same errors via [`CoreError`] which converts
+/// to and from `core::num::ParseFloatError`. Older versions of `core` differ in which
+/// strings they accept, for instance they reject `"infinity"`.
+///
+/// ```rust
+/// use core::num::ParseFloatError;
+/// use fast_float::CoreError;
+///
+/// fn parse(s: &str) -> Result<f64, ParseFloatError> {
+///     Ok(fast_float::parse_core(s)?)
+/// }
+///
+/// assert_eq!(parse("-1.5e3"), "-1.5e3".parse::<f64>());
+/// assert_eq!(parse("").unwrap_err(), "".parse::<f64>().unwrap_err());
+/// assert_eq!(parse(" 1").unwrap_err(), " 1".parse::<f64>().unwrap_err());
+/// assert_eq!(fast_float::parse_core::<f64, _>(""), Err(CoreError::Empty));
+/// assert_eq!(fast_float::parse_core::<f64, _>("+"), Err(CoreError::Invalid));
+/// ```
+///
+/// # Errors
+///
+/// Will return [`CoreError::Empty`] if the string is empty, or [`CoreError::Invalid`]
+/// if it is not a valid float literal.
+#[inline]
+pub fn parse_core<T: FastFloat, S: AsRef<[u8]>>(s: S) -> core::result::Result<T, CoreError> {
+    T::parse_float_core(s)
+}
+
+/// Parse a Fortran `Fw.d` field from the start of a fixed-width record.
+///
+/// The field consists of the first `width` characters of the record, or of the whole record
+/// if it's shorter. Leading, trailing and embedded blanks are ignored (a blank field is zero),
+/// and the field is otherwise a number accepted by [`ParseOptions::fortran`]. If there's no
+/// decimal point in the field, the last `decimals` digits of the mantissa are the fractional
+/// part. The implied point is applied to the decimal exponent before rounding, so the result
+/// is correctly rounded.
+///
+/// Returns the resulting number along with the number of characters processed, which is
+/// where the next field of the record starts:
+///
+/// ```rust
+/// let record = "   12345 -1.5D+02  314-2";
+/// let (x, n) = fast_float::parse_fortran_field::<f64, _>(record, 8, 3).unwrap();
+/// assert_eq!((x, n), (12.345, 8));
+/// let (y, m) = fast_float::parse_fortran_field::<f64, _>(&record[n..], 10, 3).unwrap();
+/// assert_eq!((y, m), (-150., 10));
+/// let (z, _) = fast_float::parse_fortran_field::<f64, _>(&record[n + m..], 10, 2).unwrap();
+/// assert_eq!(z, 3.14e-2);
+/// ```
+///
+/// # Errors
+///
+/// Will return an error if the field is not a valid Fortran real number.
+#[inline]
+pub fn parse_fortran_field<T: FastFloat, S: AsRef<[u8]>>(
+    s: S,
+    width: usize,
+    decimals: usize,
+) -> Result<(T, usize)> {
+    T::parse_fortran_field(s, width, decimals)
+}
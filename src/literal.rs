@@ -0,0 +1,95 @@
+use crate::float::Float;
+use crate::options::{DigitSeparator, ParseOptions};
+use crate::parse::parse_float;
+
+/// Type suffix of a Rust float literal like `1.5f32`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RustSuffix {
+    /// The `f32` suffix.
+    F32,
+    /// The `f64` suffix.
+    F64,
+}
+
+const RUST_OPTIONS: ParseOptions = ParseOptions::new()
+    .allow_special_values(false)
+    .digit_separator(DigitSeparator::permissive(b'_'));
+
+#[inline]
+fn is_ident_start(c: u8) -> bool {
+    // any non-ASCII character is treated as a possible identifier character
+    c.is_ascii_alphabetic() || c == b'_' || c >= 0x80
+}
+
+#[inline]
+fn is_ident_continue(c: u8) -> bool {
+    is_ident_start(c) || c.is_ascii_digit()
+}
+
+#[inline]
+fn skip_digits(s: &[u8], mut i: usize) -> (usize, bool) {
+    // skips decimal digits and underscores, returns whether there were any digits
+    let mut has_digits = false;
+    while i < s.len() && (s[i].is_ascii_digit() || s[i] == b'_') {
+        has_digits |= s[i] != b'_';
+        i += 1;
+    }
+    (i, has_digits)
+}
+
+/// Find the extent of a Rust float literal, returns the length of the number
+/// along with the total length including the suffix, and the suffix.
+#[inline]
+fn lex_rust_literal(s: &[u8]) -> Option<(usize, usize, Option<RustSuffix>)> {
+    if s.is_empty() || !s[0].is_ascii_digit() {
+        return None; // no signs, no leading dots and no special values
+    }
+    let (mut i, _) = skip_digits(s, 0);
+    let mut is_float = false;
+    if s.get(i) == Some(&b'.') {
+        match s.get(i + 1) {
+            Some(c) if c.is_ascii_digit() => {
+                i = skip_digits(s, i + 1).0;
+                is_float = true;
+            }
+            // an integer followed by a range or a field access or a method call
+            Some(&c) if c == b'.' || is_ident_start(c) => {}
+            _ => return Some((i + 1, i + 1, None)),
+        }
+    }
+    if i < s.len() && (s[i] == b'e' || s[i] == b'E') {
+        let mut j = i + 1;
+        if j < s.len() && (s[j] == b'+' || s[j] == b'-') {
+            j += 1;
+        }
+        let (j, has_digits) = skip_digits(s, j);
+        if !has_digits {
+            return None; // rustc requires at least one digit in the exponent
+        }
+        i = j;
+        is_float = true;
+    }
+
+    let suffix = match s.get(i..i + 3) {
+        Some(b"f32") => Some(RustSuffix::F32),
+        Some(b"f64") => Some(RustSuffix::F64),
+        _ => None,
+    };
+    let end = if suffix.is_some() { i + 3 } else { i };
+    match s.get(end) {
+        Some(&c) if is_ident_continue(c) => return None, // an invalid suffix
+        _ => {}
+    }
+    if !is_float && suffix.is_none() {
+        return None; // an integer literal
+    }
+    Some((i, end, suffix))
+}
+
+#[inline]
+pub fn parse_rust_literal<F: Float>(s: &[u8]) -> Option<(F, usize, Option<RustSuffix>)> {
+    let (len, end, suffix) = lex_rust_literal(s)?;
+    let (value, n) = parse_float::<F>(&s[..len], &RUST_OPTIONS)?;
+    debug_assert_eq!(n, len);
+    Some((value, end, suffix))
+}
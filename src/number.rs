@@ -1,5 +1,8 @@
+use core::cmp::Ordering;
+
 use crate::common::{is_8digits, AsciiStr, ByteSlice};
 use crate::float::Float;
+use crate::options::{DigitSeparator, ParseOptions, SpecialTokens, SpecialValue};
 
 const MIN_19DIGIT_INT: u64 = 100_0000_0000_0000_0000;
 
@@ -9,17 +12,17 @@ pub const INT_POW10: [u64; 16] = [
     100,
     1000,
     10000,
-    100000,
-    1000000,
-    10000000,
-    100000000,
-    1000000000,
-    10000000000,
-    100000000000,
-    1000000000000,
-    10000000000000,
-    100000000000000,
-    1000000000000000,
+    100_000,
+    1_000_000,
+    10_000_000,
+    100_000_000,
+    1_000_000_000,
+    10_000_000_000,
+    100_000_000_000,
+    1_000_000_000_000,
+    10_000_000_000_000,
+    100_000_000_000_000,
+    1_000_000_000_000_000,
 ];
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
@@ -67,6 +70,33 @@ impl Number {
             None
         }
     }
+
+    /// How the magnitude of a fast path result compares to the exact value of the number.
+    #[inline]
+    pub fn fast_path_rounding<F: Float>(&self, value: F) -> Ordering {
+        // both sides fit into 128 bits since fast path values are normal and not too large
+        if self.mantissa == 0 {
+            return Ordering::Equal;
+        }
+        let mantissa_bits = F::MANTISSA_EXPLICIT_BITS;
+        let bits = value.to_u64_bits();
+        let power2 = ((bits >> mantissa_bits) & F::INFINITE_POWER as u64) as i32;
+        let mantissa = (bits & ((1_u64 << mantissa_bits) - 1)) | (1_u64 << mantissa_bits);
+        let exp2 = power2 + F::MINIMUM_EXPONENT - mantissa_bits as i32;
+        let pow10 = |n: i64| (0..n).fold(1_u128, |p, _| p * 10);
+        let (mut lhs, mut rhs) = (mantissa as u128, self.mantissa as u128);
+        if self.exponent < 0 {
+            lhs *= pow10(-self.exponent);
+        } else {
+            rhs *= pow10(self.exponent);
+        }
+        if exp2 < 0 {
+            rhs <<= -exp2;
+        } else {
+            lhs <<= exp2;
+        }
+        lhs.cmp(&rhs)
+    }
 }
 
 #[inline]
@@ -89,12 +119,20 @@ fn try_parse_digits(s: &mut AsciiStr<'_>, x: &mut u64) {
 }
 
 #[inline]
-fn try_parse_19digits(s: &mut AsciiStr<'_>, x: &mut u64) {
-    while *x < MIN_19DIGIT_INT && !s.is_empty() && s.first().is_ascii_digit() {
-        let digit = s.first() - b'0';
-        *x = (*x * 10) + digit as u64; // no overflows here
+fn try_parse_19digits(s: &mut AsciiStr<'_>, x: &mut u64, sep: Option<DigitSeparator>) -> i64 {
+    // separators have already been validated, so they can just be skipped here
+    let mut n_digits = 0;
+    while *x < MIN_19DIGIT_INT && !s.is_empty() {
+        let c = s.first();
+        if c.is_ascii_digit() {
+            *x = (*x * 10) + (c - b'0') as u64; // no overflows here
+            n_digits += 1;
+        } else if sep.map(|sep| sep.separator) != Some(c) {
+            break;
+        }
         s.step();
     }
+    n_digits
 }
 
 #[inline]
@@ -119,22 +157,66 @@ fn try_parse_8digits(s: &mut AsciiStr<'_>, x: &mut u64) {
 }
 
 #[inline]
-fn parse_scientific(s: &mut AsciiStr<'_>) -> i64 {
-    // the first character is 'e'/'E' and scientific mode is enabled
+fn try_skip_separators(s: &mut AsciiStr<'_>, sep: DigitSeparator, after_digit: bool) -> bool {
+    if !s.check_first(sep.separator) {
+        return false;
+    }
+    let mut p = *s;
+    while p.check_first(sep.separator) {
+        p.step();
+    }
+    if (!sep.consecutive && p.offset_from(s) > 1)
+        || (!sep.leading && !after_digit)
+        || (!sep.trailing && !p.check_first_digit())
+    {
+        return false; // the number ends right before the separator
+    }
+    *s = p;
+    true
+}
+
+#[inline]
+fn try_parse_separated_digits(s: &mut AsciiStr<'_>, x: &mut u64, sep: DigitSeparator) -> isize {
+    // runs of digits between separators still go through the 8-digit path
+    let mut n_digits = 0;
+    loop {
+        let before = *s;
+        try_parse_8digits(s, x);
+        try_parse_digits(s, x);
+        n_digits += s.offset_from(&before);
+        if !try_skip_separators(s, sep, n_digits != 0) {
+            return n_digits;
+        }
+    }
+}
+
+#[inline]
+fn parse_scientific(s: &mut AsciiStr<'_>, marker: bool, sep: Option<DigitSeparator>) -> i64 {
+    // the first character is either 'e'/'E' or, if there's no marker, the exponent sign
     let start = *s;
-    s.step();
+    if marker {
+        s.step();
+    }
     let mut exp_num = 0_i64;
     let mut neg_exp = false;
     if !s.is_empty() && s.first_either(b'-', b'+') {
         neg_exp = s.first_is(b'-');
         s.step();
     }
-    if s.check_first_digit() {
+    let mut n_digits = 0;
+    loop {
         s.parse_digits(|digit| {
             if exp_num < 0x10000 {
                 exp_num = 10 * exp_num + digit as i64; // no overflows here
             }
+            n_digits += 1;
         });
+        match sep {
+            Some(sep) if try_skip_separators(s, sep, n_digits != 0) => {}
+            _ => break,
+        }
+    }
+    if n_digits != 0 {
         if neg_exp {
             -exp_num
         } else {
@@ -147,7 +229,7 @@ fn parse_scientific(s: &mut AsciiStr<'_>) -> i64 {
 }
 
 #[inline]
-pub fn parse_number(s: &[u8]) -> Option<(Number, usize)> {
+pub fn parse_number(s: &[u8], options: &ParseOptions) -> Option<(Number, usize)> {
     debug_assert!(!s.is_empty());
 
     let mut s = AsciiStr::new(s);
@@ -160,7 +242,7 @@ pub fn parse_number(s: &[u8]) -> Option<(Number, usize)> {
         if s.step().is_empty() {
             return None;
         }
-    } else if s.first() == b'+' && s.step().is_empty() {
+    } else if s.first() == b'+' && options.leading_plus && s.step().is_empty() {
         return None;
     }
     debug_assert!(!s.is_empty());
@@ -168,20 +250,38 @@ pub fn parse_number(s: &[u8]) -> Option<(Number, usize)> {
     // parse initial digits before dot
     let mut mantissa = 0_u64;
     let digits_start = s;
-    try_parse_digits(&mut s, &mut mantissa);
-    let mut n_digits = s.offset_from(&digits_start);
+    let mut n_digits = if !options.leading_zeros && s.check_first(b'0') {
+        s.step(); // the number may only continue with a dot or an exponent
+        1
+    } else if let Some(sep) = options.integer_separator() {
+        try_parse_separated_digits(&mut s, &mut mantissa, sep)
+    } else {
+        try_parse_digits(&mut s, &mut mantissa);
+        s.offset_from(&digits_start)
+    };
+    let n_before_dot = n_digits as i64;
+    if n_digits == 0 && !options.leading_point {
+        return None;
+    }
 
     // handle dot with the following digits
     let mut n_after_dot = 0;
     let mut exponent = 0_i64;
     let int_end = s;
-    if s.check_first(b'.') {
+    if s.check_first(options.decimal_point) {
         s.step();
-        let before = s;
-        try_parse_8digits(&mut s, &mut mantissa);
-        try_parse_digits(&mut s, &mut mantissa);
-        n_after_dot = s.offset_from(&before);
+        n_after_dot = if let Some(sep) = options.fraction_separator() {
+            try_parse_separated_digits(&mut s, &mut mantissa, sep)
+        } else {
+            let before = s;
+            try_parse_8digits(&mut s, &mut mantissa);
+            try_parse_digits(&mut s, &mut mantissa);
+            s.offset_from(&before)
+        };
         exponent = -n_after_dot as i64;
+        if n_after_dot == 0 && !options.trailing_point {
+            s = int_end; // the number ends right before the dot
+        }
     }
 
     n_digits += n_after_dot;
@@ -191,8 +291,9 @@ pub fn parse_number(s: &[u8]) -> Option<(Number, usize)> {
 
     // handle scientific format
     let mut exp_number = 0_i64;
-    if s.check_first_either(b'e', b'E') {
-        exp_number = parse_scientific(&mut s);
+    let marker = !s.is_empty() && options.is_exponent(s.first());
+    if marker || (options.implicit_exponent && s.check_first_either(b'-', b'+')) {
+        exp_number = parse_scientific(&mut s, marker, options.exponent_separator());
         exponent += exp_number;
     }
 
@@ -214,8 +315,10 @@ pub fn parse_number(s: &[u8]) -> Option<(Number, usize)> {
     n_digits -= 19;
     let mut many_digits = false;
     let mut p = digits_start;
-    while p.check_first_either(b'0', b'.') {
-        n_digits -= p.first().saturating_sub(b'0' - 1) as isize; // '0' = b'.' + 2
+    while p.check_first_either(b'0', options.decimal_point)
+        || (!p.is_empty() && options.is_separator(p.first()))
+    {
+        n_digits -= p.first_is(b'0') as isize;
         p.step();
     }
     if n_digits > 0 {
@@ -223,15 +326,13 @@ pub fn parse_number(s: &[u8]) -> Option<(Number, usize)> {
         many_digits = true;
         mantissa = 0;
         let mut s = digits_start;
-        try_parse_19digits(&mut s, &mut mantissa);
+        let n_int = try_parse_19digits(&mut s, &mut mantissa, options.integer_separator());
         exponent = if mantissa >= MIN_19DIGIT_INT {
-            int_end.offset_from(&s) // big int
+            n_before_dot - n_int // big int
         } else {
-            s.step(); // fractional component, skip the '.'
-            let before = s;
-            try_parse_19digits(&mut s, &mut mantissa);
-            -s.offset_from(&before)
-        } as i64;
+            s.step(); // fractional component, skip the dot
+            -try_parse_19digits(&mut s, &mut mantissa, options.fraction_separator())
+        };
         exponent += exp_number; // add back the explicit part
     }
 
@@ -247,7 +348,7 @@ pub fn parse_number(s: &[u8]) -> Option<(Number, usize)> {
 }
 
 #[inline]
-pub fn parse_inf_nan<F: Float>(s: &[u8]) -> Option<(F, usize)> {
+pub fn parse_inf_nan<F: Float>(s: &[u8], options: &ParseOptions) -> Option<(F, usize)> {
     fn parse_inf_rest(s: &[u8]) -> usize {
         if s.len() >= 8 && s[3..].eq_ignore_case(b"inity") {
             8
@@ -255,28 +356,84 @@ pub fn parse_inf_nan<F: Float>(s: &[u8]) -> Option<(F, usize)> {
             3
         }
     }
-    if s.len() >= 3 {
-        if s.eq_ignore_case(b"nan") {
-            return Some((F::NAN, 3));
-        } else if s.eq_ignore_case(b"inf") {
-            return Some((F::INFINITY, parse_inf_rest(s)));
-        } else if s.len() >= 4 {
-            if s.get_first() == b'+' {
-                let s = s.advance(1);
-                if s.eq_ignore_case(b"nan") {
-                    return Some((F::NAN, 4));
-                } else if s.eq_ignore_case(b"inf") {
-                    return Some((F::INFINITY, 1 + parse_inf_rest(s)));
-                }
-            } else if s.get_first() == b'-' {
-                let s = s.advance(1);
-                if s.eq_ignore_case(b"nan") {
-                    return Some((F::NEG_NAN, 4));
-                } else if s.eq_ignore_case(b"inf") {
-                    return Some((F::NEG_INFINITY, 1 + parse_inf_rest(s)));
-                }
-            }
+    let (negative, start) = match s.first() {
+        Some(&b'-') => (true, 1),
+        Some(&b'+') if options.leading_plus => (false, 1),
+        _ => (false, 0),
+    };
+    let s = s.advance(start);
+    if s.len() < 3 {
+        return None;
+    }
+    let (quiet, n) = if s.eq_ignore_case(b"inf") {
+        let value = if negative {
+            F::NEG_INFINITY
+        } else {
+            F::INFINITY
+        };
+        return Some((value, start + parse_inf_rest(s)));
+    } else if s.eq_ignore_case(b"nan") {
+        (true, 3)
+    } else if options.nan_payload && s.len() >= 4 && s.eq_ignore_case(b"snan") {
+        (false, 4)
+    } else {
+        return None;
+    };
+    if !options.nan_payload {
+        let value = if negative { F::NEG_NAN } else { F::NAN };
+        return Some((value, start + n));
+    }
+    let (payload, m) = parse_nan_payload(s.advance(n)).unwrap_or((0, 0));
+    Some((F::nan_with_payload(negative, quiet, payload), start + n + m))
+}
+
+/// Parse a NaN payload like `"(0x1f)"`, where the integer inside the parentheses is read
+/// the same way as C's `strtoull` with base 0 does, saturating on overflow.
+#[inline]
+pub fn parse_nan_payload(s: &[u8]) -> Option<(u64, usize)> {
+    if !s.check_first(b'(') {
+        return None;
+    }
+    let n = s[1..].iter().position(|&c| c == b')')?;
+    let digits = &s[1..=n];
+    let (radix, digits) = if digits.len() > 2 && digits[0] == b'0' && digits[1] | 0x20 == b'x' {
+        (16, &digits[2..])
+    } else if digits.len() > 1 && digits[0] == b'0' {
+        (8, &digits[1..])
+    } else {
+        (10, digits)
+    };
+    if digits.is_empty() {
+        return None;
+    }
+    let mut payload = 0_u64;
+    for &c in digits {
+        let digit = u64::from((c as char).to_digit(radix)?);
+        payload = payload
+            .checked_mul(u64::from(radix))
+            .and_then(|p| p.checked_add(digit))
+            .unwrap_or(0xFFFF_FFFF_FFFF_FFFF);
+    }
+    Some((payload, n + 2))
+}
+
+#[inline]
+pub fn parse_special_token<F: Float>(s: &[u8], tokens: &SpecialTokens) -> Option<(F, usize)> {
+    // the longest matching token wins
+    let mut best: Option<(SpecialValue, usize)> = None;
+    for &(token, value) in tokens.tokens {
+        let (token, n) = (token.as_bytes(), token.len());
+        if n == 0 || n > s.len() || n <= best.map_or(0, |(_, m)| m) {
+            continue;
+        }
+        let matches = if tokens.case_sensitive {
+            &s[..n] == token
+        } else {
+            s[..n].eq_ignore_ascii_case(token)
+        };
+        if matches {
+            best = Some((value, n));
         }
     }
-    None
+    best.map(|(value, n)| (value.to_float(), n))
 }
@@ -0,0 +1,569 @@
+use crate::float::Float;
+
+/// Options controlling the number grammar accepted by the parser.
+///
+/// The default options accept exactly the same inputs as [`parse`](crate::parse()) and
+/// [`parse_partial`](crate::parse_partial()): `'.'` as the decimal point, an optional
+/// `'+'` or `'-'` sign, `'e'` or `'E'` as the exponent marker, numbers like `"1."` and `".5"`,
+/// and case-insensitive `inf`, `infinity` and `nan` special values.
+///
+/// Options are constructed via [`ParseOptions::new`] and then adjusted by chaining
+/// `const` setter methods, so they can be stored in constants:
+///
+/// ```rust
+/// use fast_float::ParseOptions;
+///
+/// const OPTIONS: ParseOptions = ParseOptions::new()
+///     .decimal_point(b',')
+///     .allow_leading_plus(false)
+///     .allow_special_values(false);
+///
+/// let x: f64 = fast_float::parse_with_options("-1,5e3", &OPTIONS).unwrap();
+/// assert_eq!(x, -1500.);
+/// assert!(fast_float::parse_with_options::<f64, _>("+1,5", &OPTIONS).is_err());
+/// assert!(fast_float::parse_with_options::<f64, _>("inf", &OPTIONS).is_err());
+/// ```
+#[must_use]
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseOptions {
+    pub(crate) decimal_point: u8,
+    pub(crate) exponent: &'static [u8],
+    pub(crate) implicit_exponent: bool,
+    pub(crate) leading_plus: bool,
+    pub(crate) leading_point: bool,
+    pub(crate) trailing_point: bool,
+    pub(crate) leading_zeros: bool,
+    pub(crate) special_values: bool,
+    pub(crate) nan_payload: bool,
+    pub(crate) out_of_range: bool,
+    pub(crate) special_tokens: Option<SpecialTokens>,
+    pub(crate) digit_separator: Option<DigitSeparator>,
+    pub(crate) whitespace: Whitespace,
+}
+
+impl Default for ParseOptions {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParseOptions {
+    /// Create the default options, matching the grammar of [`parse`](crate::parse()).
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            decimal_point: b'.',
+            exponent: b"eE",
+            implicit_exponent: false,
+            leading_plus: true,
+            leading_point: true,
+            trailing_point: true,
+            leading_zeros: true,
+            special_values: true,
+            nan_payload: false,
+            out_of_range: true,
+            special_tokens: None,
+            digit_separator: None,
+            whitespace: Whitespace::None,
+        }
+    }
+
+    /// Create the options for strict JSON numbers as defined by RFC 8259.
+    ///
+    /// Only `'-'` is accepted as a sign, both the integer and the fractional parts must have
+    /// digits if present, redundant leading zeros are not accepted and neither are the special
+    /// values. Partial parsing stops exactly where the JSON number ends:
+    ///
+    /// ```rust
+    /// use fast_float::ParseOptions;
+    ///
+    /// const JSON: ParseOptions = ParseOptions::json();
+    ///
+    /// let x: f64 = fast_float::parse_with_options("-0.5e+3", &JSON).unwrap();
+    /// assert_eq!(x, -500.);
+    /// for s in &["+1", "01", "1.", ".5", "inf", "nan"] {
+    ///     assert!(fast_float::parse_with_options::<f64, _>(s, &JSON).is_err());
+    /// }
+    /// let (x, n) = fast_float::parse_partial_with_options::<f64, _>("01", &JSON).unwrap();
+    /// assert_eq!((x, n), (0., 1));
+    /// ```
+    #[inline]
+    pub const fn json() -> Self {
+        Self::new()
+            .allow_leading_plus(false)
+            .allow_leading_point(false)
+            .allow_trailing_point(false)
+            .allow_leading_zeros(false)
+            .allow_special_values(false)
+    }
+
+    /// Create the options for real numbers as written by Fortran programs.
+    ///
+    /// Besides `'e'`, the exponent may be marked by `'d'` (double precision) or `'q'`
+    /// (quadruple precision) in either case, or by its sign alone, as in `"1.234-100"`
+    /// where the `'E'` is dropped to fit a three-digit exponent into the field:
+    ///
+    /// ```rust
+    /// use fast_float::ParseOptions;
+    ///
+    /// const FORTRAN: ParseOptions = ParseOptions::fortran();
+    ///
+    /// for s in &["1.0D+05", "1.0d5", "1.0Q+05", "1.0E+05", "1.0+05", "100000."] {
+    ///     let x: f64 = fast_float::parse_with_options(s, &FORTRAN).unwrap();
+    ///     assert_eq!(x, 1e5);
+    /// }
+    /// let x: f64 = fast_float::parse_with_options("1.234-100", &FORTRAN).unwrap();
+    /// assert_eq!(x, 1.234e-100);
+    /// ```
+    #[inline]
+    pub const fn fortran() -> Self {
+        Self::new()
+            .exponent(b"eEdDqQ")
+            .allow_implicit_exponent(true)
+    }
+
+    /// Set the byte separating the integer and the fractional parts (`'.'` by default).
+    ///
+    /// The decimal point must not be an ASCII digit, a sign or an exponent character.
+    /// Both the fast path and the slow path for long mantissas respect this setting, so
+    /// the results are rounded identically regardless of the decimal point used:
+    ///
+    /// ```rust
+    /// use fast_float::ParseOptions;
+    ///
+    /// let options = ParseOptions::new().decimal_point(b',');
+    /// let x: f64 = fast_float::parse_with_options("3,14159", &options).unwrap();
+    /// assert_eq!(x, fast_float::parse::<f64, _>("3.14159").unwrap());
+    /// ```
+    #[inline]
+    pub const fn decimal_point(self, decimal_point: u8) -> Self {
+        Self {
+            decimal_point,
+            ..self
+        }
+    }
+
+    /// Set the characters which may start the exponent part (`b"eE"` by default).
+    ///
+    /// Passing an empty slice disables scientific notation.
+    #[inline]
+    pub const fn exponent(self, exponent: &'static [u8]) -> Self {
+        Self { exponent, ..self }
+    }
+
+    /// Whether a sign right after the digits starts the exponent part like in `"1.5-3"`,
+    /// with no exponent character in between (`false` by default).
+    ///
+    /// A sign which isn't followed by any exponent digits is not part of the number.
+    #[inline]
+    pub const fn allow_implicit_exponent(self, allow: bool) -> Self {
+        Self {
+            implicit_exponent: allow,
+            ..self
+        }
+    }
+
+    /// Whether an explicit leading `'+'` sign is accepted (`true` by default).
+    #[inline]
+    pub const fn allow_leading_plus(self, allow: bool) -> Self {
+        Self {
+            leading_plus: allow,
+            ..self
+        }
+    }
+
+    /// Whether numbers without integer digits like `".5"` are accepted (`true` by default).
+    #[inline]
+    pub const fn allow_leading_point(self, allow: bool) -> Self {
+        Self {
+            leading_point: allow,
+            ..self
+        }
+    }
+
+    /// Whether numbers without fractional digits like `"1."` are accepted (`true` by default).
+    ///
+    /// If disabled, the number is considered to end right before the decimal point.
+    #[inline]
+    pub const fn allow_trailing_point(self, allow: bool) -> Self {
+        Self {
+            trailing_point: allow,
+            ..self
+        }
+    }
+
+    /// Whether integer parts with redundant leading zeros like `"01"` are accepted
+    /// (`true` by default).
+    ///
+    /// If disabled, the number is considered to end right after the first zero.
+    #[inline]
+    pub const fn allow_leading_zeros(self, allow: bool) -> Self {
+        Self {
+            leading_zeros: allow,
+            ..self
+        }
+    }
+
+    /// Whether the special values `inf`, `infinity` and `nan` are accepted (`true` by default).
+    #[inline]
+    pub const fn allow_special_values(self, allow: bool) -> Self {
+        Self {
+            special_values: allow,
+            ..self
+        }
+    }
+
+    /// Whether `nan` may be followed by a payload in parentheses and whether the signaling
+    /// `snan` is accepted (`false` by default).
+    ///
+    /// The payload is a decimal, octal (leading `0`) or hexadecimal (leading `0x`) integer,
+    /// as in C's `nan()`, and its low bits are stored in the mantissa of the resulting NaN.
+    /// Text in parentheses which isn't an integer is not consumed.
+    ///
+    /// ```rust
+    /// use fast_float::ParseOptions;
+    ///
+    /// const OPTIONS: ParseOptions = ParseOptions::new().allow_nan_payload(true);
+    ///
+    /// let x: f64 = fast_float::parse_with_options("nan(0x1f)", &OPTIONS).unwrap();
+    /// assert_eq!(x.to_bits(), 0x7ff8_0000_0000_001f);
+    /// let x: f32 = fast_float::parse_with_options("-snan", &OPTIONS).unwrap();
+    /// assert_eq!(x.to_bits(), 0xffa0_0000);
+    /// assert!(fast_float::parse_with_options::<f64, _>("nan(abc)", &OPTIONS).is_err());
+    /// ```
+    #[inline]
+    pub const fn allow_nan_payload(self, allow: bool) -> Self {
+        Self {
+            nan_payload: allow,
+            ..self
+        }
+    }
+
+    /// Whether numbers which overflow to infinity, underflow to zero or become subnormal
+    /// are accepted (`true` by default).
+    ///
+    /// This is the strict counterpart of checking `errno == ERANGE` after C's `strtod`:
+    /// such numbers are rejected like invalid ones, see [`RangeStatus`](crate::RangeStatus)
+    /// for the exact conditions. Zero and the special values are always in range.
+    ///
+    /// ```rust
+    /// use fast_float::ParseOptions;
+    ///
+    /// const STRICT: ParseOptions = ParseOptions::new().allow_out_of_range(false);
+    ///
+    /// assert_eq!(fast_float::parse_with_options::<f64, _>("1e300", &STRICT), Ok(1e300));
+    /// assert_eq!(fast_float::parse_with_options::<f64, _>("0e-400", &STRICT), Ok(0.));
+    /// assert!(fast_float::parse_with_options::<f64, _>("1e400", &STRICT).is_err());
+    /// assert!(fast_float::parse_with_options::<f64, _>("1e-400", &STRICT).is_err());
+    /// assert!(fast_float::parse_with_options::<f64, _>("1e-310", &STRICT).is_err());
+    /// assert!(fast_float::parse_with_options::<f32, _>("1e300", &STRICT).is_err());
+    /// ```
+    #[inline]
+    pub const fn allow_out_of_range(self, allow: bool) -> Self {
+        Self {
+            out_of_range: allow,
+            ..self
+        }
+    }
+
+    /// Replace the special values `inf`, `infinity` and `nan` with a custom table of tokens,
+    /// which also enables the special values if they were disabled.
+    ///
+    /// ```rust
+    /// use fast_float::{ParseOptions, SpecialTokens, SpecialValue};
+    ///
+    /// const OPTIONS: ParseOptions = ParseOptions::new().special_tokens(SpecialTokens::new(&[
+    ///     ("1.#INF", SpecialValue::Infinity),
+    ///     ("-1.#INF", SpecialValue::NegInfinity),
+    ///     ("1.#QNAN", SpecialValue::NaN),
+    ///     ("#N/A", SpecialValue::NaN),
+    /// ]));
+    ///
+    /// let x: f64 = fast_float::parse_with_options("-1.#INF", &OPTIONS).unwrap();
+    /// assert_eq!(x, core::f64::NEG_INFINITY);
+    /// assert!(fast_float::parse_with_options::<f64, _>("#N/A", &OPTIONS).unwrap().is_nan());
+    /// assert!(fast_float::parse_with_options::<f64, _>("inf", &OPTIONS).is_err());
+    /// assert_eq!(fast_float::parse_with_options::<f64, _>("1.5", &OPTIONS).unwrap(), 1.5);
+    /// ```
+    #[inline]
+    pub const fn special_tokens(self, tokens: SpecialTokens) -> Self {
+        Self {
+            special_values: true,
+            special_tokens: Some(tokens),
+            ..self
+        }
+    }
+
+    /// Set the digit group separator which may appear between digits (none by default).
+    #[inline]
+    pub const fn digit_separator(self, separator: DigitSeparator) -> Self {
+        Self {
+            digit_separator: Some(separator),
+            ..self
+        }
+    }
+
+    /// Set which whitespace is skipped before and after the number (none by default).
+    ///
+    /// Trailing whitespace is counted as processed by the partial parsing functions, so the
+    /// full parsing functions accept numbers followed by whitespace only:
+    ///
+    /// ```rust
+    /// use fast_float::{ParseOptions, Whitespace};
+    ///
+    /// let options = ParseOptions::new().whitespace(Whitespace::Ascii);
+    /// let x: f64 = fast_float::parse_with_options(" 1.5\n", &options).unwrap();
+    /// assert_eq!(x, 1.5);
+    /// let (x, n) = fast_float::parse_partial_with_options::<f64, _>("\t1.5  2.5", &options).unwrap();
+    /// assert_eq!((x, n), (1.5, 6));
+    /// ```
+    #[inline]
+    pub const fn whitespace(self, whitespace: Whitespace) -> Self {
+        Self { whitespace, ..self }
+    }
+
+    #[inline]
+    pub(crate) fn is_exponent(&self, c: u8) -> bool {
+        self.exponent.contains(&c)
+    }
+
+    #[inline]
+    pub(crate) fn is_separator(&self, c: u8) -> bool {
+        self.digit_separator.map(|sep| sep.separator) == Some(c)
+    }
+
+    #[inline]
+    pub(crate) fn integer_separator(&self) -> Option<DigitSeparator> {
+        self.digit_separator.filter(|sep| sep.integer)
+    }
+
+    #[inline]
+    pub(crate) fn fraction_separator(&self) -> Option<DigitSeparator> {
+        self.digit_separator.filter(|sep| sep.fraction)
+    }
+
+    #[inline]
+    pub(crate) fn exponent_separator(&self) -> Option<DigitSeparator> {
+        self.digit_separator.filter(|sep| sep.exponent)
+    }
+}
+
+/// Value of a special token, see [`SpecialTokens`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpecialValue {
+    /// Positive infinity.
+    Infinity,
+    /// Negative infinity.
+    NegInfinity,
+    /// NaN with the sign bit cleared.
+    NaN,
+    /// NaN with the sign bit set.
+    NegNaN,
+}
+
+impl SpecialValue {
+    #[inline]
+    pub(crate) fn to_float<F: Float>(self) -> F {
+        match self {
+            SpecialValue::Infinity => F::INFINITY,
+            SpecialValue::NegInfinity => F::NEG_INFINITY,
+            SpecialValue::NaN => F::NAN,
+            SpecialValue::NegNaN => F::NEG_NAN,
+        }
+    }
+}
+
+/// Table of custom spellings of the special values, see [`ParseOptions::special_tokens`].
+///
+/// Each token is matched literally including its sign (there's no implicit sign), either
+/// case-sensitively (by default) or ignoring the case of ASCII letters. When several tokens
+/// match, the longest one wins. Empty tokens never match.
+///
+/// The table is only consulted when the input doesn't start with a number, or when the
+/// number ends right at its decimal point (like the `"1."` in `"1.#INF"`), so it doesn't
+/// slow down the parsing of ordinary numbers, even when they're followed by something else.
+/// Therefore, a token which starts like a number has to continue right after its point.
+#[must_use]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SpecialTokens {
+    pub(crate) tokens: &'static [(&'static str, SpecialValue)],
+    pub(crate) case_sensitive: bool,
+}
+
+impl SpecialTokens {
+    /// Create a table of case-sensitive tokens.
+    #[inline]
+    pub const fn new(tokens: &'static [(&'static str, SpecialValue)]) -> Self {
+        Self {
+            tokens,
+            case_sensitive: true,
+        }
+    }
+
+    /// Whether the tokens are matched case-sensitively (`true` by default).
+    #[inline]
+    pub const fn case_sensitive(self, case_sensitive: bool) -> Self {
+        Self {
+            case_sensitive,
+            ..self
+        }
+    }
+}
+
+/// Whitespace which may surround a number, see [`ParseOptions::whitespace`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Whitespace {
+    /// No whitespace is accepted.
+    None,
+    /// ASCII whitespace: `' '`, `'\t'`, `'\n'`, `'\x0B'`, `'\x0C'` and `'\r'`, like
+    /// `isspace()` in the "C" locale.
+    Ascii,
+    /// Unicode whitespace as defined by [`char::is_whitespace`], which includes the ASCII
+    /// whitespace and e.g. the no-break spaces U+00A0 and U+202F.
+    Unicode,
+}
+
+impl Default for Whitespace {
+    #[inline]
+    fn default() -> Self {
+        Whitespace::None
+    }
+}
+
+impl Whitespace {
+    #[inline]
+    pub(crate) fn is_space(self, c: char) -> bool {
+        match self {
+            Whitespace::None => false,
+            Whitespace::Ascii => c == ' ' || ('\t'..='\r').contains(&c),
+            Whitespace::Unicode => c.is_whitespace(),
+        }
+    }
+}
+
+/// Digit group separator (thousands separator) accepted within the digits of a number.
+///
+/// By default, the separator is accepted both in the integer and the fractional parts
+/// (and optionally in the exponent), but only between two digits; separators before
+/// the first digit or after the last digit of a part, as well as several separators
+/// in a row, can be allowed explicitly.
+/// When a separator is not accepted at some position, the number ends right before it.
+///
+/// ```rust
+/// use fast_float::{DigitSeparator, ParseOptions};
+///
+/// let options = ParseOptions::new().digit_separator(DigitSeparator::new(b','));
+/// let x: f64 = fast_float::parse_with_options("1,234,567.89", &options).unwrap();
+/// assert_eq!(x, 1234567.89);
+///
+/// let options = ParseOptions::new()
+///     .decimal_point(b',')
+///     .digit_separator(DigitSeparator::new(b' ').fraction(false));
+/// let x: f64 = fast_float::parse_with_options("1 234 567,8", &options).unwrap();
+/// assert_eq!(x, 1234567.8);
+/// ```
+#[must_use]
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DigitSeparator {
+    pub(crate) separator: u8,
+    pub(crate) integer: bool,
+    pub(crate) fraction: bool,
+    pub(crate) exponent: bool,
+    pub(crate) leading: bool,
+    pub(crate) trailing: bool,
+    pub(crate) consecutive: bool,
+}
+
+impl DigitSeparator {
+    /// Create a digit separator accepted between digits of both the integer and
+    /// the fractional parts.
+    ///
+    /// The separator must not be an ASCII digit, a sign, an exponent character or the
+    /// decimal point.
+    #[inline]
+    pub const fn new(separator: u8) -> Self {
+        Self {
+            separator,
+            integer: true,
+            fraction: true,
+            exponent: false,
+            leading: false,
+            trailing: false,
+            consecutive: false,
+        }
+    }
+
+    /// A separator accepted anywhere within the digits of every part, for grammars
+    /// which validate the placement of separators before parsing.
+    #[inline]
+    pub(crate) const fn permissive(separator: u8) -> Self {
+        Self {
+            separator,
+            integer: true,
+            fraction: true,
+            exponent: true,
+            leading: true,
+            trailing: true,
+            consecutive: true,
+        }
+    }
+
+    /// Whether the separator is accepted in the integer part (`true` by default).
+    #[inline]
+    pub const fn integer(self, allow: bool) -> Self {
+        Self {
+            integer: allow,
+            ..self
+        }
+    }
+
+    /// Whether the separator is accepted in the fractional part (`true` by default).
+    #[inline]
+    pub const fn fraction(self, allow: bool) -> Self {
+        Self {
+            fraction: allow,
+            ..self
+        }
+    }
+
+    /// Whether the separator is accepted in the exponent part (`false` by default).
+    #[inline]
+    pub const fn exponent(self, allow: bool) -> Self {
+        Self {
+            exponent: allow,
+            ..self
+        }
+    }
+
+    /// Whether the separator is accepted before the first digit of a part, like
+    /// in `"_1"` or `"1._5"` (`false` by default).
+    #[inline]
+    pub const fn allow_leading(self, allow: bool) -> Self {
+        Self {
+            leading: allow,
+            ..self
+        }
+    }
+
+    /// Whether the separator is accepted after the last digit of a part, like
+    /// in `"1_"` or `"1_.5"` (`false` by default).
+    #[inline]
+    pub const fn allow_trailing(self, allow: bool) -> Self {
+        Self {
+            trailing: allow,
+            ..self
+        }
+    }
+
+    /// Whether several separators in a row like in `"1__000"` are accepted (`false` by default).
+    #[inline]
+    pub const fn allow_consecutive(self, allow: bool) -> Self {
+        Self {
+            consecutive: allow,
+            ..self
+        }
+    }
+}
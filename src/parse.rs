@@ -1,34 +1,137 @@
+use core::cmp::Ordering;
+
 use crate::binary::compute_float;
 use crate::float::Float;
-use crate::number::{parse_inf_nan, parse_number};
+use crate::number::{parse_inf_nan, parse_number, parse_special_token};
+use crate::options::{ParseOptions, Whitespace};
+use crate::range::RangeStatus;
 use crate::simple::parse_long_mantissa;
+use crate::whitespace::trim_start;
+
+#[inline]
+pub fn parse_float<F: Float>(s: &[u8], options: &ParseOptions) -> Option<(F, usize)> {
+    parse_float_with_range(s, options).map(|(value, n, _)| (value, n))
+}
+
+#[inline]
+pub fn parse_float_with_range<F: Float>(
+    s: &[u8],
+    options: &ParseOptions,
+) -> Option<(F, usize, RangeStatus)> {
+    parse_float_rounded(s, options, false).map(|(value, n, range, _)| (value, n, range))
+}
 
+/// Parse a float along with how its magnitude compares to the exact value of the number,
+/// which may be left unknown unless `rounding` is set.
 #[inline]
-pub fn parse_float<F: Float>(s: &[u8]) -> Option<(F, usize)> {
+pub fn parse_float_rounded<F: Float>(
+    s: &[u8],
+    options: &ParseOptions,
+    rounding: bool,
+) -> Option<(F, usize, RangeStatus, Option<Ordering>)> {
+    if options.whitespace == Whitespace::None {
+        return parse_float_impl(s, options, 0, rounding);
+    }
+    // both the leading and the trailing whitespace count as processed
+    let is_space = |c| options.whitespace.is_space(c);
+    let start = s.len() - trim_start(s, is_space).len();
+    let (value, n, range, ord) = parse_float_impl(&s[start..], options, 0, rounding)?;
+    let end = start + n;
+    let rest = &s[end..];
+    Some((
+        value,
+        end + rest.len() - trim_start(rest, is_space).len(),
+        range,
+        ord,
+    ))
+}
+
+#[inline]
+fn ends_at_point(s: &[u8], rest: usize, options: &ParseOptions) -> bool {
+    // the point itself may be left unparsed if trailing points aren't allowed
+    s[rest - 1] == options.decimal_point || s[rest] == options.decimal_point
+}
+
+/// Parse a float multiplied by `10^scale`, which is applied to the decimal exponent
+/// before rounding so the result is still correctly rounded.
+#[inline]
+pub fn parse_float_scaled<F: Float>(
+    s: &[u8],
+    options: &ParseOptions,
+    scale: i64,
+) -> Option<(F, usize, RangeStatus)> {
+    parse_float_impl(s, options, scale, false).map(|(value, n, range, _)| (value, n, range))
+}
+
+#[inline]
+fn parse_float_impl<F: Float>(
+    s: &[u8],
+    options: &ParseOptions,
+    scale: i64,
+    rounding: bool,
+) -> Option<(F, usize, RangeStatus, Option<Ordering>)> {
+    const EXACT: Option<Ordering> = Some(Ordering::Equal);
+
     if s.is_empty() {
         return None;
     }
 
-    let (num, rest) = match parse_number(s) {
+    let (mut num, rest) = match parse_number(s, options) {
         Some(r) => r,
-        None => return parse_inf_nan(s),
+        None if options.special_values => {
+            return parse_special(s, options)
+                .map(|(value, n)| (value, n, RangeStatus::InRange, EXACT))
+        }
+        None => return None,
     };
+    if options.special_values && rest != s.len() && ends_at_point(s, rest, options) {
+        if let Some(tokens) = options.special_tokens {
+            // custom tokens may start like a number, as in "1.#INF"
+            match parse_special_token(s, &tokens) {
+                Some((value, n)) if n > rest => {
+                    return Some((value, n, RangeStatus::InRange, EXACT))
+                }
+                _ => {}
+            }
+        }
+    }
+    num.exponent += scale;
     if let Some(value) = num.try_fast_path::<F>() {
-        return Some((value, rest));
+        let ord = if rounding {
+            Some(num.fast_path_rounding(value))
+        } else {
+            None
+        };
+        return Some((value, rest, RangeStatus::InRange, ord));
     }
 
-    let mut am = compute_float::<F>(num.exponent, num.mantissa);
-    if num.many_digits && am != compute_float::<F>(num.exponent, num.mantissa + 1) {
-        am.power2 = -1;
+    let (mut am, mut ord) = compute_float::<F>(num.exponent, num.mantissa);
+    if num.many_digits {
+        // the exact value lies in between the two truncated mantissas
+        let (upper, upper_ord) = compute_float::<F>(num.exponent, num.mantissa + 1);
+        if am != upper {
+            am.power2 = -1;
+        } else if ord != Some(Ordering::Less) {
+            ord = upper_ord.filter(|&ord| ord == Ordering::Greater);
+        }
     }
-    if am.power2 < 0 {
-        am = parse_long_mantissa::<F>(s);
+    if am.power2 < 0 || (rounding && ord.is_none()) {
+        let (long, long_ord) = parse_long_mantissa::<F>(&s[..rest], options, scale);
+        am = long;
+        ord = Some(long_ord);
     }
-
-    let mut word = am.mantissa;
-    word |= (am.power2 as u64) << F::MANTISSA_EXPLICIT_BITS;
-    if num.negative {
-        word |= 1_u64 << F::SIGN_INDEX;
+    let range = am.range::<F>(num.mantissa != 0);
+    if !options.out_of_range && range != RangeStatus::InRange {
+        return None;
     }
-    Some((F::from_u64_bits(word), rest))
+
+    Some((am.to_float(num.negative), rest, range, ord))
+}
+
+#[inline]
+fn parse_special<F: Float>(s: &[u8], options: &ParseOptions) -> Option<(F, usize)> {
+    options.special_tokens.map_or_else(
+        || parse_inf_nan(s, options),
+        |tokens| parse_special_token(s, &tokens),
+    )
 }
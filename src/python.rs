@@ -0,0 +1,17 @@
+use crate::float::Float;
+use crate::options::{DigitSeparator, ParseOptions};
+use crate::parse::parse_float;
+use crate::whitespace::{trim_end, trim_start};
+
+const PYTHON_OPTIONS: ParseOptions =
+    ParseOptions::new().digit_separator(DigitSeparator::new(b'_').exponent(true));
+
+#[inline]
+pub fn parse_python<F: Float>(s: &[u8]) -> Option<F> {
+    // unlike str.isspace(), float() doesn't strip the ASCII separators '\x1c'..='\x1f'
+    let s = trim_end(trim_start(s, char::is_whitespace), char::is_whitespace);
+    match parse_float(s, &PYTHON_OPTIONS) {
+        Some((value, n)) if n == s.len() => Some(value),
+        _ => None,
+    }
+}
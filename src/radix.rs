@@ -0,0 +1,338 @@
+use core::cmp::Ordering;
+
+use crate::bigint::Bigint;
+use crate::common::{AdjustedMantissa, AsciiStr};
+use crate::float::Float;
+use crate::hex::HexNumber;
+use crate::options::ParseOptions;
+use crate::parse::parse_float;
+
+// decimal numbers use the regular parser, restricted to the radix grammar
+const DECIMAL_OPTIONS: ParseOptions = ParseOptions::new()
+    .exponent(b"")
+    .allow_special_values(false);
+
+#[inline]
+fn radix_digit(c: u8, radix: u32) -> Option<u8> {
+    (c as char).to_digit(radix).map(|digit| digit as u8)
+}
+
+/// Extended-precision float `mantissa * 2^exponent`, the mantissa is normalized.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct ExtendedFloat {
+    mantissa: u64,
+    exponent: i64,
+}
+
+impl ExtendedFloat {
+    #[inline]
+    fn from_u64(v: u64) -> Self {
+        debug_assert_ne!(v, 0);
+        let lz = v.leading_zeros();
+        Self {
+            mantissa: v << lz,
+            exponent: -(lz as i64),
+        }
+    }
+
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        // truncating, the relative error is below 2^-63
+        let p = (self.mantissa as u128) * (other.mantissa as u128);
+        let shift = 63 + (p >> 127) as u32;
+        Self {
+            mantissa: (p >> shift) as u64,
+            exponent: self.exponent + other.exponent + shift as i64,
+        }
+    }
+
+    #[inline]
+    fn recip(self) -> Self {
+        if self.mantissa == 1 << 63 {
+            Self {
+                mantissa: 1 << 63,
+                exponent: -126 - self.exponent,
+            }
+        } else {
+            Self {
+                mantissa: ((1_u128 << 127) / self.mantissa as u128) as u64,
+                exponent: -127 - self.exponent,
+            }
+        }
+    }
+
+    #[inline]
+    fn pow(radix: u32, mut n: u64) -> Self {
+        let mut value = Self::from_u64(1);
+        let mut base = Self::from_u64(radix as u64);
+        while n != 0 {
+            if n & 1 != 0 {
+                value = value.mul(base);
+            }
+            base = base.mul(base);
+            n >>= 1;
+        }
+        value
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RadixNumber<'a> {
+    pub radix: u32,
+    pub negative: bool,
+    pub integer: &'a [u8],
+    pub fraction: &'a [u8],
+}
+
+impl<'a> RadixNumber<'a> {
+    #[inline]
+    fn digits(self) -> impl Iterator<Item = u8> + Clone + 'a {
+        let radix = self.radix;
+        let digits = self.integer.iter().chain(self.fraction.iter());
+        digits.map(move |&c| radix_digit(c, radix).unwrap_or(0))
+    }
+
+    /// Significant digits `d1, d2, ...` along with `point` such that the number
+    /// equals `0.d1d2... * radix^point`.
+    #[inline]
+    fn significant_digits(self) -> (impl Iterator<Item = u8> + Clone + 'a, i64) {
+        let n_zeros = self.digits().take_while(|&digit| digit == 0).count();
+        let point = self.integer.len() as i64 - n_zeros as i64;
+        (self.digits().skip(n_zeros), point)
+    }
+
+    #[inline]
+    fn to_hex_number(self) -> HexNumber {
+        let bits = self.radix.trailing_zeros();
+        let mut num = HexNumber::default();
+        for &c in self.integer {
+            num.push_digit(radix_digit(c, self.radix).unwrap_or(0), bits, false);
+        }
+        for &c in self.fraction {
+            num.push_digit(radix_digit(c, self.radix).unwrap_or(0), bits, true);
+        }
+        num
+    }
+
+    /// Leading digits accumulated into a 64-bit integer `w` along with `n` such that
+    /// the number is approximately `w * radix^n`, and whether this is exact.
+    #[inline]
+    fn leading_digits(self) -> (u64, i64, bool) {
+        let (digits, point) = self.significant_digits();
+        let limit = 0xFFFF_FFFF_FFFF_FFFF_u64 / self.radix as u64;
+        let (mut w, mut n_digits, mut exact) = (0_u64, 0_i64, true);
+        for digit in digits {
+            if w >= limit {
+                exact = false;
+                break;
+            }
+            w = w * self.radix as u64 + digit as u64;
+            n_digits += 1;
+        }
+        (w, point - n_digits, exact)
+    }
+
+    #[inline]
+    fn try_fast_path<F: Float>(self) -> Option<F> {
+        // both operands are exact, so the result is correctly rounded
+        let (w, n, exact) = self.leading_digits();
+        if !exact || w > F::MAX_MANTISSA_FAST_PATH || !(-64..=64).contains(&n) {
+            return None;
+        }
+        let pow = if n < 0 { (-n) as u32 } else { n as u32 };
+        let scale = (self.radix as u64).checked_pow(pow)?;
+        if scale > F::MAX_MANTISSA_FAST_PATH {
+            return None;
+        }
+        let mut value = if n < 0 {
+            F::from_u64(w) / F::from_u64(scale)
+        } else {
+            F::from_u64(w) * F::from_u64(scale)
+        };
+        if self.negative {
+            value = -value;
+        }
+        Some(value)
+    }
+
+    /// Compare the number to the halfway point `mantissa * 2^exponent`.
+    #[inline]
+    fn compare_halfway(self, mantissa: u64, exponent: i32) -> Ordering {
+        let (digits, point) = self.significant_digits();
+
+        // the halfway point divided by radix^point, as an exact fraction
+        let mut num = Bigint::from_u64(mantissa);
+        let mut den = Bigint::from_u64(1);
+        if exponent > 0 {
+            num.mul_pow2(exponent as u32);
+        } else {
+            den.mul_pow2(-exponent as u32);
+        }
+        if point > 0 {
+            den.mul_pow(self.radix, point as u32);
+        } else {
+            num.mul_pow(self.radix, -point as u32);
+        }
+        if num.compare(&den) != Ordering::Less {
+            return Ordering::Less;
+        }
+
+        // generate digits of the halfway point and compare them to ours
+        for digit in digits {
+            num.mul_small(self.radix);
+            let mut expected = 0;
+            while num.compare(&den) != Ordering::Less {
+                num.sub_assign(&den);
+                expected += 1;
+            }
+            if digit != expected {
+                return digit.cmp(&expected);
+            }
+        }
+        if num.is_zero() {
+            Ordering::Equal
+        } else {
+            Ordering::Less
+        }
+    }
+
+    /// Whether the number rounds to a float above the one with the given bits.
+    #[inline]
+    fn rounds_above<F: Float>(self, word: u64) -> bool {
+        let mut mantissa = word & ((1 << F::MANTISSA_EXPLICIT_BITS) - 1);
+        let power2 = (word >> F::MANTISSA_EXPLICIT_BITS) as i32;
+        let exponent = if power2 == 0 {
+            F::MINIMUM_EXPONENT + 1 - F::MANTISSA_EXPLICIT_BITS as i32
+        } else {
+            mantissa |= 1 << F::MANTISSA_EXPLICIT_BITS;
+            power2 + F::MINIMUM_EXPONENT - F::MANTISSA_EXPLICIT_BITS as i32
+        };
+        match self.compare_halfway(2 * mantissa + 1, exponent - 1) {
+            Ordering::Greater => true,
+            Ordering::Equal => word & 1 != 0, // ties to even
+            Ordering::Less => false,
+        }
+    }
+
+    #[inline]
+    pub fn to_adjusted_mantissa<F: Float>(self) -> AdjustedMantissa {
+        let am_zero = AdjustedMantissa::zero_pow2(0);
+        let am_inf = AdjustedMantissa::zero_pow2(F::INFINITE_POWER);
+
+        if self.radix.is_power_of_two() {
+            return self.to_hex_number().to_adjusted_mantissa::<F>();
+        }
+        let (w, n, _) = self.leading_digits();
+        if w == 0 {
+            return am_zero;
+        }
+
+        // approximate the number, the relative error is below (2 * log2(n) + 4) * 2^-63
+        // from the multiplications plus radix * 2^-64 from the truncated digits
+        let scale = if n < 0 {
+            ExtendedFloat::pow(self.radix, (-n) as u64).recip()
+        } else {
+            ExtendedFloat::pow(self.radix, n as u64)
+        };
+        let approx = ExtendedFloat::from_u64(w).mul(scale);
+        let log2 = approx.exponent + 64;
+        if log2 < (F::MINIMUM_EXPONENT - F::MANTISSA_EXPLICIT_BITS as i32 - 2) as i64 {
+            return am_zero;
+        } else if log2 > (F::INFINITE_POWER + F::MINIMUM_EXPONENT + 2) as i64 {
+            return am_inf;
+        }
+        let margin = 8 * (64 - n.abs().leading_zeros() as u64 + 2) + 2 * self.radix as u64;
+        let round = |mantissa: u64| {
+            let num = HexNumber {
+                exponent: approx.exponent,
+                mantissa,
+                negative: false,
+                truncated: false,
+            };
+            num.to_adjusted_mantissa::<F>()
+        };
+        let am = round(approx.mantissa);
+        if let Some(upper) = approx.mantissa.checked_add(margin) {
+            if am == round(approx.mantissa - margin) && am == round(upper) {
+                return am;
+            }
+        }
+
+        // the approximation is within an ulp or so, fix it up using exact comparisons
+        let mut word = ((am.power2 as u64) << F::MANTISSA_EXPLICIT_BITS) | am.mantissa;
+        let max_word = (F::INFINITE_POWER as u64) << F::MANTISSA_EXPLICIT_BITS;
+        while word < max_word && self.rounds_above::<F>(word) {
+            word += 1;
+        }
+        while word > 0 && !self.rounds_above::<F>(word - 1) {
+            word -= 1;
+        }
+        AdjustedMantissa {
+            mantissa: word & ((1 << F::MANTISSA_EXPLICIT_BITS) - 1),
+            power2: (word >> F::MANTISSA_EXPLICIT_BITS) as i32,
+        }
+    }
+}
+
+#[inline]
+fn parse_radix_digits(s: &mut AsciiStr<'_>, radix: u32) {
+    while !s.is_empty() && radix_digit(s.first(), radix).is_some() {
+        s.step();
+    }
+}
+
+#[inline]
+pub fn parse_radix_number(s: &[u8], radix: u32) -> Option<(RadixNumber<'_>, usize)> {
+    let bytes = s;
+    let mut s = AsciiStr::new(s);
+    let start = s;
+    let mut negative = false;
+
+    // handle optional +/- sign
+    if s.check_first_either(b'-', b'+') {
+        negative = s.first_is(b'-');
+        s.step();
+    }
+
+    // parse digits before and after the dot
+    let int_start = s.offset_from(&start) as usize;
+    parse_radix_digits(&mut s, radix);
+    let int_end = s.offset_from(&start) as usize;
+    let mut frac_start = int_end;
+    if s.check_first(b'.') {
+        s.step();
+        frac_start += 1;
+        parse_radix_digits(&mut s, radix);
+    }
+    let frac_end = s.offset_from(&start) as usize;
+    if int_start == int_end && frac_start == frac_end {
+        return None;
+    }
+
+    let num = RadixNumber {
+        radix,
+        negative,
+        integer: &bytes[int_start..int_end],
+        fraction: &bytes[frac_start..frac_end],
+    };
+    Some((num, frac_end))
+}
+
+#[inline]
+pub fn parse_radix_float<F: Float>(s: &[u8], radix: u32) -> Option<(F, usize)> {
+    assert!(
+        (2..=36).contains(&radix),
+        "radix must lie in the range `[2, 36]` - found {}",
+        radix
+    );
+    if radix == 10 {
+        return parse_float(s, &DECIMAL_OPTIONS);
+    }
+
+    let (num, rest) = parse_radix_number(s, radix)?;
+    if let Some(value) = num.try_fast_path::<F>() {
+        return Some((value, rest));
+    }
+    let am = num.to_adjusted_mantissa::<F>();
+    Some((am.to_float(num.negative), rest))
+}
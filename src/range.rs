@@ -0,0 +1,30 @@
+use core::fmt;
+
+/// Whether a parsed number fits the range of the float type, like `ERANGE` in C.
+///
+/// Zero and the special values are always in range; a number which rounds to the largest
+/// finite value or to the smallest normal one is in range as well.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RangeStatus {
+    /// The number is zero, normal or a special value.
+    InRange,
+    /// The number is too large in magnitude and became an infinity.
+    Overflow,
+    /// The number is nonzero but too small in magnitude and became a zero.
+    Underflow,
+    /// The number is too small in magnitude to be normal and became a nonzero subnormal,
+    /// which has less precision.
+    Subnormal,
+}
+
+impl fmt::Display for RangeStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            RangeStatus::InRange => "in range",
+            RangeStatus::Overflow => "overflows to infinity",
+            RangeStatus::Underflow => "underflows to zero",
+            RangeStatus::Subnormal => "is subnormal",
+        };
+        f.write_str(s)
+    }
+}
@@ -1,9 +1,18 @@
+use core::cmp::Ordering;
+
 use crate::common::AdjustedMantissa;
 use crate::decimal::{parse_decimal, Decimal};
 use crate::float::Float;
+use crate::options::ParseOptions;
 
+/// Parse the mantissa with the slow algorithm, along with how the magnitude of the result
+/// compares to the exact value.
 #[inline]
-pub fn parse_long_mantissa<F: Float>(s: &[u8]) -> AdjustedMantissa {
+pub fn parse_long_mantissa<F: Float>(
+    s: &[u8],
+    options: &ParseOptions,
+    scale: i64,
+) -> (AdjustedMantissa, Ordering) {
     const MAX_SHIFT: usize = 60;
     const NUM_POWERS: usize = 19;
     const POWERS: [u8; 19] = [
@@ -18,16 +27,23 @@ pub fn parse_long_mantissa<F: Float>(s: &[u8]) -> AdjustedMantissa {
         }
     };
 
-    let am_zero = AdjustedMantissa::zero_pow2(0);
-    let am_inf = AdjustedMantissa::zero_pow2(F::INFINITE_POWER);
+    let am_zero = (AdjustedMantissa::zero_pow2(0), Ordering::Less);
+    let am_inf = (
+        AdjustedMantissa::zero_pow2(F::INFINITE_POWER),
+        Ordering::Greater,
+    );
 
-    let mut d = parse_decimal(s);
+    let mut d = parse_decimal(s, options);
+    let decimal_point = d.decimal_point as i64 + scale;
 
-    if d.num_digits == 0 || d.decimal_point < -324 {
+    if d.num_digits == 0 {
+        return (AdjustedMantissa::zero_pow2(0), Ordering::Equal);
+    } else if decimal_point < -324 {
         return am_zero;
-    } else if d.decimal_point >= 310 {
+    } else if decimal_point >= 310 {
         return am_inf;
     }
+    d.decimal_point = decimal_point as i32;
     let mut exp2 = 0_i32;
     while d.decimal_point > 0 {
         let n = d.decimal_point as usize;
@@ -81,5 +97,11 @@ pub fn parse_long_mantissa<F: Float>(s: &[u8]) -> AdjustedMantissa {
         power2 -= 1;
     }
     mantissa &= (1_u64 << F::MANTISSA_EXPLICIT_BITS) - 1;
-    AdjustedMantissa { mantissa, power2 }
+    // all of the digits may have been shifted out for the tiniest subnormals
+    let ord = if d.num_digits == 0 {
+        Ordering::Less
+    } else {
+        d.rounding()
+    };
+    (AdjustedMantissa { mantissa, power2 }, ord)
 }
@@ -0,0 +1,51 @@
+use crate::common::ByteSlice;
+use crate::float::Float;
+use crate::hex::parse_hex_float;
+use crate::number::parse_nan_payload;
+use crate::options::ParseOptions;
+use crate::parse::parse_float;
+
+#[inline]
+fn is_c_space(c: u8) -> bool {
+    // ' ', '\t', '\n', '\v', '\f' and '\r', as per isspace() in the "C" locale
+    c == b' ' || (b'\t'..=b'\r').contains(&c)
+}
+
+#[inline]
+fn parse_nan_chars(s: &[u8]) -> usize {
+    // "(n-char-sequence)" following "nan", or nothing if it's not closed
+    if !s.check_first(b'(') {
+        return 0;
+    }
+    let n = s[1..]
+        .iter()
+        .take_while(|&&c| c.is_ascii_alphanumeric() || c == b'_')
+        .count();
+    if s.get(n + 1) == Some(&b')') {
+        n + 2
+    } else {
+        0
+    }
+}
+
+#[inline]
+#[allow(clippy::manual_let_else)] // let-else needs Rust 1.65
+pub fn parse_strtod<F: Float>(s: &[u8]) -> (F, usize) {
+    let n_spaces = s.iter().take_while(|&&c| is_c_space(c)).count();
+    let s = &s[n_spaces..];
+
+    // "0x" without any hex digits is just a zero followed by 'x'
+    let parsed = parse_hex_float(s).or_else(|| parse_float(s, &ParseOptions::new()));
+    let (mut value, mut n) = match parsed {
+        Some(r) => r,
+        None => return (F::default(), 0),
+    };
+    if n >= 3 && s[n - 3..].eq_ignore_case(b"nan") {
+        // only a sequence which is an integer as a whole sets the payload
+        if let Some((payload, _)) = parse_nan_payload(&s[n..]) {
+            value = F::nan_with_payload(s.check_first(b'-'), true, payload);
+        }
+        n += parse_nan_chars(&s[n..]);
+    }
+    (value, n_spaces + n)
+}
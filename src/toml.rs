@@ -0,0 +1,31 @@
+use crate::float::Float;
+use crate::options::{DigitSeparator, ParseOptions, SpecialTokens, SpecialValue};
+use crate::parse::parse_float;
+
+const TOML_OPTIONS: ParseOptions = ParseOptions::new()
+    .allow_leading_point(false)
+    .allow_trailing_point(false)
+    .allow_leading_zeros(false)
+    .special_tokens(SpecialTokens::new(&[
+        ("inf", SpecialValue::Infinity),
+        ("+inf", SpecialValue::Infinity),
+        ("-inf", SpecialValue::NegInfinity),
+        ("nan", SpecialValue::NaN),
+        ("+nan", SpecialValue::NaN),
+        ("-nan", SpecialValue::NegNaN),
+    ]))
+    .digit_separator(DigitSeparator::new(b'_').exponent(true));
+
+#[inline]
+pub fn parse_toml<F: Float>(s: &[u8]) -> Option<F> {
+    // without a fraction or an exponent it would be an integer, not a float
+    if s.iter()
+        .all(|&c| c.is_ascii_digit() || c == b'_' || c == b'+' || c == b'-')
+    {
+        return None;
+    }
+    match parse_float(s, &TOML_OPTIONS) {
+        Some((value, n)) if n == s.len() => Some(value),
+        _ => None,
+    }
+}
@@ -0,0 +1,58 @@
+use core::str;
+
+#[inline]
+pub fn decode(s: &[u8]) -> Option<char> {
+    // decodes the first char of a UTF-8 sequence, ignoring anything after it
+    let s = &s[..s.len().min(4)];
+    let s = match str::from_utf8(s) {
+        Ok(s) => s,
+        Err(e) => str::from_utf8(&s[..e.valid_up_to()]).ok()?,
+    };
+    s.chars().next()
+}
+
+/// Skip leading whitespace, where the predicate defines what whitespace is.
+#[inline]
+pub fn trim_start(mut s: &[u8], is_space: impl Fn(char) -> bool) -> &[u8] {
+    while let Some(&c) = s.first() {
+        if c < 0x80 {
+            if !is_space(c as char) {
+                break;
+            }
+            s = &s[1..];
+        } else {
+            match decode(s) {
+                Some(c) if is_space(c) => s = &s[c.len_utf8()..],
+                _ => break,
+            }
+        }
+    }
+    s
+}
+
+/// Skip trailing whitespace, where the predicate defines what whitespace is.
+#[inline]
+pub fn trim_end(mut s: &[u8], is_space: impl Fn(char) -> bool) -> &[u8] {
+    while let Some(&c) = s.last() {
+        if c < 0x80 {
+            if !is_space(c as char) {
+                break;
+            }
+            s = &s[..s.len() - 1];
+        } else {
+            // find the start of the last UTF-8 sequence
+            let n = s
+                .iter()
+                .rev()
+                .take(4)
+                .take_while(|&&c| c & 0xC0 == 0x80)
+                .count();
+            let start = s.len() - (n + 1).min(s.len());
+            match decode(&s[start..]) {
+                Some(c) if is_space(c) && c.len_utf8() == s.len() - start => s = &s[..start],
+                _ => break,
+            }
+        }
+    }
+    s
+}
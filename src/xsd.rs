@@ -0,0 +1,27 @@
+use crate::float::Float;
+use crate::options::{ParseOptions, SpecialTokens, SpecialValue};
+use crate::parse::parse_float;
+use crate::whitespace::{trim_end, trim_start};
+
+// (noDecimalPtNumeral | decimalPtNumeral) ([eE] noDecimalPtNumeral)?
+const XSD_OPTIONS: ParseOptions = ParseOptions::new().special_tokens(SpecialTokens::new(&[
+    ("INF", SpecialValue::Infinity),
+    ("+INF", SpecialValue::Infinity),
+    ("-INF", SpecialValue::NegInfinity),
+    ("NaN", SpecialValue::NaN),
+]));
+
+#[inline]
+fn is_xml_space(c: char) -> bool {
+    // whiteSpace="collapse" strips the XML whitespace characters at both ends
+    c == ' ' || c == '\t' || c == '\n' || c == '\r'
+}
+
+#[inline]
+pub fn parse_xsd<F: Float>(s: &[u8]) -> Option<F> {
+    let s = trim_end(trim_start(s, is_xml_space), is_xml_space);
+    match parse_float(s, &XSD_OPTIONS) {
+        Some((value, n)) if n == s.len() => Some(value),
+        _ => None,
+    }
+}
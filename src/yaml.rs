@@ -0,0 +1,27 @@
+use crate::float::Float;
+use crate::options::{ParseOptions, SpecialTokens, SpecialValue};
+use crate::parse::parse_float;
+
+// [-+]? ( \. [0-9]+ | [0-9]+ ( \. [0-9]* )? ) ( [eE] [-+]? [0-9]+ )?
+const YAML_OPTIONS: ParseOptions = ParseOptions::new().special_tokens(SpecialTokens::new(&[
+    (".inf", SpecialValue::Infinity),
+    (".Inf", SpecialValue::Infinity),
+    (".INF", SpecialValue::Infinity),
+    ("+.inf", SpecialValue::Infinity),
+    ("+.Inf", SpecialValue::Infinity),
+    ("+.INF", SpecialValue::Infinity),
+    ("-.inf", SpecialValue::NegInfinity),
+    ("-.Inf", SpecialValue::NegInfinity),
+    ("-.INF", SpecialValue::NegInfinity),
+    (".nan", SpecialValue::NaN),
+    (".NaN", SpecialValue::NaN),
+    (".NAN", SpecialValue::NaN),
+]));
+
+#[inline]
+pub fn parse_yaml<F: Float>(s: &[u8]) -> Option<F> {
+    match parse_float(s, &YAML_OPTIONS) {
+        Some((value, n)) if n == s.len() => Some(value),
+        _ => None,
+    }
+}
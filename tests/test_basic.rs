@@ -36,6 +36,11 @@ macro_rules! check {
                 assert_eq!(result, std);
             }
         }
+        // the same input should round identically with a decimal comma
+        let comma = string.replace('.', ",");
+        let options = fast_float::ParseOptions::new().decimal_point(b',');
+        let result = fast_float::parse_with_options::<$ty, _>(&comma, &options).unwrap();
+        assert_eq!(result, expected);
     }};
 }
 macro_rules! check_lex {
@@ -0,0 +1,193 @@
+use fast_float::{parse_c_literal, parse_c_literal_partial, CSuffix, FastFloat};
+
+macro_rules! check_ok {
+    ($s:expr, $x:expr) => {
+        check_ok!($s, $x, None);
+    };
+    ($s:expr, $x:expr, $suffix:expr) => {
+        let s = $s;
+        assert_eq!(
+            parse_c_literal::<f64, _>(s).unwrap(),
+            ($x, $suffix),
+            "{:?}",
+            s
+        );
+        assert_eq!(
+            f32::parse_c_literal(s.as_bytes()).unwrap(),
+            ($x, $suffix),
+            "{:?}",
+            s
+        );
+        assert_eq!(
+            parse_c_literal_partial::<f64, _>(s).unwrap(),
+            ($x, s.len(), $suffix),
+            "{:?}",
+            s
+        );
+    };
+}
+
+macro_rules! check_ok_partial {
+    ($s:expr, $x:expr, $n:expr) => {
+        check_ok_partial!($s, $x, $n, None);
+    };
+    ($s:expr, $x:expr, $n:expr, $suffix:expr) => {
+        let s = $s;
+        assert!(parse_c_literal::<f64, _>(s).is_err(), "{:?}", s);
+        assert_eq!(
+            parse_c_literal_partial::<f64, _>(s).unwrap(),
+            ($x, $n, $suffix),
+            "{:?}",
+            s
+        );
+        assert_eq!(
+            parse_c_literal_partial::<f32, _>(s).unwrap(),
+            ($x, $n, $suffix),
+            "{:?}",
+            s
+        );
+    };
+}
+
+macro_rules! check_err {
+    ($s:expr) => {
+        let s = $s;
+        assert!(parse_c_literal::<f64, _>(s).is_err(), "{:?}", s);
+        assert!(parse_c_literal_partial::<f64, _>(s).is_err(), "{:?}", s);
+        assert!(f32::parse_c_literal_partial(s).is_err(), "{:?}", s);
+    };
+}
+
+#[test]
+fn test_c_literal_decimal() {
+    check_ok!("1.5", 1.5);
+    check_ok!("1.", 1.);
+    check_ok!(".5", 0.5);
+    check_ok!("1e3", 1e3);
+    check_ok!("1E-3", 1e-3);
+    check_ok!("2.5e+2", 2.5e+2);
+    check_ok!(".5e1", 5.);
+    check_ok!("1.e1", 10.);
+    check_ok!("00.5", 0.5);
+    check_ok!("1'000.5", 1_000.5);
+    check_ok!("1'000'000.000'001", 1_000_000.000_001);
+    check_ok!("1.5e1'0", 1.5e10);
+}
+
+#[test]
+fn test_c_literal_hex() {
+    check_ok!("0x1p0", 1.);
+    check_ok!("0x1.8p1", 3.);
+    check_ok!("0X.8P-1", 0.25);
+    check_ok!("0x1.p4", 16.);
+    check_ok!("0xAp0", 10.);
+    check_ok!("0x1'0p0", 16.);
+    check_ok!("0x1.8p10f", 1536., Some(CSuffix::Float));
+    check_err!("0x1");
+    check_err!("0x1.8");
+    check_err!("0xp1");
+    check_err!("0x.p1");
+    check_err!("0x1p");
+    check_err!("0x1p+");
+    check_err!("0x'1p0");
+    check_err!("0x1p'1");
+}
+
+#[test]
+fn test_c_literal_suffix() {
+    check_ok!("1.5f", 1.5, Some(CSuffix::Float));
+    check_ok!("1.5F", 1.5, Some(CSuffix::Float));
+    check_ok!("1.5l", 1.5, Some(CSuffix::LongDouble));
+    check_ok!("1.5L", 1.5, Some(CSuffix::LongDouble));
+    check_ok!("1.5d", 1.5, Some(CSuffix::Double));
+    check_ok!("1.5D", 1.5, Some(CSuffix::Double));
+    check_ok!("1.5f16", 1.5, Some(CSuffix::Float16));
+    check_ok!("1.5F16", 1.5, Some(CSuffix::Float16));
+    check_ok!("1.5bf16", 1.5, Some(CSuffix::BFloat16));
+    check_ok!("1.5BF16", 1.5, Some(CSuffix::BFloat16));
+    check_ok!("1.f", 1., Some(CSuffix::Float));
+    check_ok!("1e5f16", 1e5, Some(CSuffix::Float16));
+    check_ok!("0x1p-1L", 0.5, Some(CSuffix::LongDouble));
+    check_ok!("1f", 1., Some(CSuffix::Float));
+    check_ok!("2D", 2., Some(CSuffix::Double));
+    check_err!("1");
+    check_err!("1L");
+    check_err!("1f16");
+    check_err!("1.5ff");
+    check_err!("1.5lf");
+    check_err!("1.5fl");
+    check_err!("1.5f32");
+    check_err!("1.5bf");
+    check_err!("1.5u");
+    check_err!("1.5_km");
+    check_err!("1.5'f");
+}
+
+#[test]
+fn test_c_literal_partial() {
+    check_ok_partial!("1.5;", 1.5, 3);
+    check_ok_partial!("1.5f)", 1.5, 4, Some(CSuffix::Float));
+    check_ok_partial!("1. + 2.", 1., 2);
+    check_ok_partial!("1e5-2", 1e5, 3);
+    check_ok_partial!("2.5e+3f*x", 2500., 7, Some(CSuffix::Float));
+    check_ok_partial!("0x1p-2,", 0.25, 6);
+    check_ok_partial!("1'0.5'", 10.5, 5);
+}
+
+#[test]
+fn test_c_literal_errors() {
+    check_err!("");
+    check_err!("-1.5");
+    check_err!("+1.5");
+    check_err!(".");
+    check_err!(".e5");
+    check_err!("'1.5");
+    check_err!("1'.5");
+    check_err!("1.'5");
+    check_err!("1''0.5");
+    check_err!("1e'5");
+    check_err!("1.5.0");
+    check_err!("1..2");
+    check_err!("1e");
+    check_err!("1e+");
+    check_err!("1.5e");
+    check_err!("1_000.5");
+    check_err!("inf");
+    check_err!("NaN");
+    check_err!("1.5é");
+}
+
+#[test]
+fn test_c_literal_random() {
+    let rng = fastrand::Rng::with_seed(0);
+    for _ in 0..10_000 {
+        let digits = |n: usize| -> String {
+            let mut s = rng.char('0'..='9').to_string();
+            for _ in 0..n {
+                if rng.u8(..4) == 0 {
+                    s.push('\'');
+                }
+                s.push(rng.char('0'..='9'));
+            }
+            s
+        };
+        let mut s = digits(rng.usize(..10));
+        s += ".";
+        s += &digits(rng.usize(..30));
+        if rng.bool() {
+            s += if rng.bool() { "e-" } else { "E" };
+            s += &digits(rng.usize(..2));
+        }
+        let expected: f64 = s.replace('\'', "").parse().unwrap();
+        assert_eq!(
+            parse_c_literal::<f64, _>(&s).unwrap(),
+            (expected, None),
+            "{}",
+            s
+        );
+        s += "f";
+        let expected: f32 = s[..s.len() - 1].replace('\'', "").parse().unwrap();
+        let actual = parse_c_literal::<f32, _>(&s).unwrap();
+        assert_eq!(actual, (expected, Some(CSuffix::Float)), "{}", s);
+    }
+}
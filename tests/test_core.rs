@@ -0,0 +1,249 @@
+#![allow(clippy::excessive_precision, clippy::legacy_numeric_constants)]
+
+use core::num::ParseFloatError;
+
+use fast_float::{parse_core, CoreError, FastFloat};
+
+// compare with str::parse() from core, which follows the same grammar since Rust 1.55
+#[cfg(core_float_grammar)]
+fn check_core(s: &str) {
+    let (x, y) = (parse_core::<f64, _>(s), s.parse::<f64>());
+    match (&x, &y) {
+        (Ok(a), Ok(b)) => assert_eq!(a.to_bits(), b.to_bits(), "{:?}", s),
+        _ => assert_eq!(x, y.map_err(CoreError::from), "{:?}", s),
+    }
+    let (x, y) = (f32::parse_float_core(s), s.parse::<f32>());
+    match (&x, &y) {
+        (Ok(a), Ok(b)) => assert_eq!(a.to_bits(), b.to_bits(), "{:?}", s),
+        _ => assert_eq!(x, y.map_err(CoreError::from), "{:?}", s),
+    }
+}
+
+#[cfg(not(core_float_grammar))]
+fn check_core(_: &str) {}
+
+fn check(s: &str, expected: Result<f64, CoreError>) {
+    // the expected values are pinned as well, so older toolchains still test something
+    let x = parse_core::<f64, _>(s);
+    match (&x, &expected) {
+        (Ok(a), Ok(b)) => assert_eq!(a.to_bits(), b.to_bits(), "{:?}", s),
+        _ => assert_eq!(x, expected, "{:?}", s),
+    }
+    let x = f32::parse_float_core(s).map(|_| ());
+    assert_eq!(x, expected.map(|_| ()), "{:?}", s);
+    check_core(s);
+}
+
+#[test]
+fn test_core_edge_cases() {
+    use CoreError::{Empty, Invalid};
+
+    let inf = core::f64::INFINITY;
+    for &(s, expected) in &[
+        ("", Err(Empty)),
+        ("+", Err(Invalid)),
+        ("-", Err(Invalid)),
+        (".", Err(Invalid)),
+        ("+.", Err(Invalid)),
+        ("-.", Err(Invalid)),
+        ("e", Err(Invalid)),
+        ("e5", Err(Invalid)),
+        (".e5", Err(Invalid)),
+        ("1e", Err(Invalid)),
+        ("1e+", Err(Invalid)),
+        ("1e-", Err(Invalid)),
+        ("1.e", Err(Invalid)),
+        ("1", Ok(1.)),
+        ("-1", Ok(-1.)),
+        ("+1", Ok(1.)),
+        ("1.", Ok(1.)),
+        (".5", Ok(0.5)),
+        ("-.5", Ok(-0.5)),
+        ("+1.5e+5", Ok(1.5e5)),
+        ("1.5E-5", Ok(1.5e-5)),
+        ("00012", Ok(12.)),
+        ("0.0", Ok(0.)),
+        ("-0.0", Ok(-0.)),
+        ("+0", Ok(0.)),
+        ("1e0", Ok(1.)),
+        ("1e-0", Ok(1.)),
+        ("1e+00000000000000000000", Ok(1.)),
+        ("1e99999999999999999999", Ok(inf)),
+        ("1e-99999999999999999999", Ok(0.)),
+        ("0e99999999999999999999", Ok(0.)),
+        ("1.7976931348623157e308", Ok(1.7976931348623157e308)),
+        ("1.7976931348623159e308", Ok(inf)),
+        ("2.2250738585072011e-308", Ok(2.2250738585072011e-308)),
+        ("4.9406564584124654e-324", Ok(5e-324)),
+        ("2.4703282292062328e-324", Ok(5e-324)),
+        ("2.4703282292062327e-324", Ok(0.)),
+        ("3.4028235e38", Ok(3.4028235e38)),
+        ("3.4028236e38", Ok(3.4028236e38)),
+        ("1.17549435e-38", Ok(1.17549435e-38)),
+        ("1.4e-45", Ok(1.4e-45)),
+        ("7e-46", Ok(7e-46)),
+        ("9007199254740993", Ok(9007199254740992.)),
+        ("0.1", Ok(0.1)),
+        ("123456789012345678901234567890", Ok(1.2345678901234568e29)),
+        ("1..5", Err(Invalid)),
+        ("1.5.", Err(Invalid)),
+        ("--1", Err(Invalid)),
+        ("+-1", Err(Invalid)),
+        ("-+1", Err(Invalid)),
+        (" 1", Err(Invalid)),
+        ("1 ", Err(Invalid)),
+        ("1_0", Err(Invalid)),
+        ("1,5", Err(Invalid)),
+        ("0x10", Err(Invalid)),
+        ("1d5", Err(Invalid)),
+        ("1e5.5", Err(Invalid)),
+        ("1e+-5", Err(Invalid)),
+        ("1f", Err(Invalid)),
+        ("\u{0}1", Err(Invalid)),
+        ("1\u{0}", Err(Invalid)),
+        ("١", Err(Invalid)),
+    ] {
+        check(s, expected);
+    }
+    let s = format!("0.{}1e325", "0".repeat(1000));
+    check(&s, Ok(0.));
+    let s = format!("0.{}1e1101", "0".repeat(1000));
+    check(&s, Ok(1e100));
+    let s = format!("{}.{}", "9".repeat(400), "9".repeat(400));
+    check(&s, Ok(inf));
+}
+
+#[test]
+fn test_core_special() {
+    // core only accepts all of these since Rust 1.55
+    for &s in &[
+        "inf",
+        "+inf",
+        "Inf",
+        "INF",
+        "infinity",
+        "+Infinity",
+        "iNfInItY",
+    ] {
+        assert_eq!(parse_core::<f64, _>(s), Ok(core::f64::INFINITY), "{:?}", s);
+        check_core(s);
+    }
+    for &s in &["-inf", "-Infinity", "-INFINITY"] {
+        assert_eq!(
+            parse_core::<f32, _>(s),
+            Ok(core::f32::NEG_INFINITY),
+            "{:?}",
+            s
+        );
+        check_core(s);
+    }
+    for &s in &["nan", "NaN", "+nan", "NAN"] {
+        let x = parse_core::<f64, _>(s).unwrap();
+        assert!(x.is_nan() && x.is_sign_positive(), "{:?}", s);
+        check_core(s);
+    }
+    let x = parse_core::<f64, _>("-nan").unwrap();
+    assert!(x.is_nan() && x.is_sign_negative());
+    check_core("-nan");
+    for &s in &[
+        "in",
+        "infinit",
+        "infinityy",
+        "inf1",
+        "nan1",
+        "nan(1)",
+        "+-inf",
+        "i nf",
+    ] {
+        assert_eq!(parse_core::<f64, _>(s), Err(CoreError::Invalid), "{:?}", s);
+        check_core(s);
+    }
+}
+
+#[test]
+fn test_core_error_conversion() {
+    let empty = "".parse::<f64>().unwrap_err();
+    let invalid = "x".parse::<f32>().unwrap_err();
+    assert_eq!(CoreError::from(empty.clone()), CoreError::Empty);
+    assert_eq!(CoreError::from(invalid.clone()), CoreError::Invalid);
+    assert_eq!(ParseFloatError::from(CoreError::Empty), empty);
+    assert_eq!(ParseFloatError::from(CoreError::Invalid), invalid);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_core_error_display() {
+    for &s in &["", "x"] {
+        let e = parse_core::<f64, _>(s).unwrap_err();
+        assert_eq!(e.to_string(), s.parse::<f64>().unwrap_err().to_string());
+    }
+}
+
+// the grammar of core since Rust 1.55, for finite numbers only
+fn is_valid(s: &str) -> bool {
+    fn skip_sign(s: &str) -> &str {
+        if s.starts_with('+') || s.starts_with('-') {
+            &s[1..]
+        } else {
+            s
+        }
+    }
+    let is_digits = |s: &str| s.bytes().all(|c| c.is_ascii_digit());
+    let s = skip_sign(s);
+    let (mantissa, exp) = match s.find(&['e', 'E'][..]) {
+        Some(i) => (&s[..i], Some(skip_sign(&s[i + 1..]))),
+        None => (s, None),
+    };
+    let mantissa_ok = match mantissa.find('.') {
+        Some(i) => is_digits(&mantissa[..i]) && is_digits(&mantissa[i + 1..]),
+        None => is_digits(mantissa),
+    };
+    let has_digits = mantissa.bytes().any(|c| c.is_ascii_digit());
+    let exp_ok = match exp {
+        Some(e) => !e.is_empty() && is_digits(e),
+        None => true,
+    };
+    mantissa_ok && has_digits && exp_ok
+}
+
+#[test]
+fn test_core_random() {
+    let rng = fastrand::Rng::with_seed(0);
+    let parts = [
+        "0",
+        "1",
+        "5",
+        "9",
+        ".",
+        "e",
+        "E",
+        "+",
+        "-",
+        "0000000000",
+        "9999999999",
+    ];
+    for _ in 0..100_000 {
+        let mut s = String::new();
+        for _ in 0..rng.usize(..10) {
+            s += parts[rng.usize(..parts.len())];
+        }
+        let expected = if s.is_empty() {
+            Err(CoreError::Empty)
+        } else if is_valid(&s) {
+            Ok(())
+        } else {
+            Err(CoreError::Invalid)
+        };
+        assert_eq!(parse_core::<f64, _>(&s).map(|_| ()), expected, "{:?}", s);
+        check_core(&s);
+    }
+    for _ in 0..10_000 {
+        let x = f64::from_bits(rng.u64(..));
+        if x.is_finite() {
+            check(&format!("{:e}", x), Ok(x));
+            let y = x as f32;
+            let z = parse_core::<f32, _>(format!("{}", y)).unwrap();
+            assert_eq!(z.to_bits(), y.to_bits(), "{}", y);
+        }
+    }
+}
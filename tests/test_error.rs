@@ -0,0 +1,402 @@
+use fast_float::{
+    parse_detailed, parse_detailed_with_options, parse_partial_detailed,
+    parse_partial_detailed_with_options, DigitSeparator, FastFloat, ParseError, ParseOptions,
+    Whitespace,
+};
+
+use ParseError::{Empty, InvalidExponent, InvalidSign, NoDigits, TrailingCharacters};
+
+macro_rules! check_err {
+    ($s:expr, $e:expr) => {
+        check_err!(&ParseOptions::new(), $s, $e);
+        let s = $s;
+        assert_eq!(parse_detailed::<f64, _>(s), Err($e), "{:?}", s);
+        assert_eq!(
+            f32::parse_float_detailed_with_options(s, &ParseOptions::new()),
+            Err($e)
+        );
+    };
+    ($opts:expr, $s:expr, $e:expr) => {
+        let (opts, s, e) = ($opts, $s, $e);
+        assert_eq!(
+            parse_detailed_with_options::<f64, _>(s, opts),
+            Err(e),
+            "{:?}",
+            s
+        );
+        assert_eq!(
+            parse_detailed_with_options::<f32, _>(s, opts),
+            Err(e),
+            "{:?}",
+            s
+        );
+        assert!(fast_float::parse_with_options::<f64, _>(s, opts).is_err());
+        let partial = parse_partial_detailed_with_options::<f64, _>(s, opts);
+        match e {
+            TrailingCharacters { consumed, .. } => assert_eq!(partial.unwrap().1, consumed),
+            InvalidExponent { .. } => assert!(partial.is_ok()),
+            _ => assert_eq!(partial, Err(e)),
+        }
+    };
+}
+
+#[test]
+fn test_error_empty() {
+    check_err!("", Empty);
+    check_err!(
+        &ParseOptions::new().whitespace(Whitespace::Ascii),
+        " \t",
+        Empty
+    );
+    check_err!(
+        " ",
+        NoDigits {
+            position: 0,
+            found: Some(' ')
+        }
+    );
+}
+
+#[test]
+fn test_error_sign() {
+    check_err!(
+        "--1",
+        InvalidSign {
+            position: 1,
+            found: '-'
+        }
+    );
+    check_err!(
+        "+-1",
+        InvalidSign {
+            position: 1,
+            found: '-'
+        }
+    );
+    check_err!(
+        "-+",
+        InvalidSign {
+            position: 1,
+            found: '+'
+        }
+    );
+    let opts = ParseOptions::new().allow_leading_plus(false);
+    check_err!(
+        &opts,
+        "+1",
+        InvalidSign {
+            position: 0,
+            found: '+'
+        }
+    );
+    check_err!(
+        &opts,
+        "+inf",
+        InvalidSign {
+            position: 0,
+            found: '+'
+        }
+    );
+    let opts = opts.whitespace(Whitespace::Ascii);
+    check_err!(
+        &opts,
+        "  +1",
+        InvalidSign {
+            position: 2,
+            found: '+'
+        }
+    );
+}
+
+#[test]
+fn test_error_no_digits() {
+    check_err!(
+        "abc",
+        NoDigits {
+            position: 0,
+            found: Some('a')
+        }
+    );
+    check_err!(
+        "-",
+        NoDigits {
+            position: 1,
+            found: None
+        }
+    );
+    check_err!(
+        "+",
+        NoDigits {
+            position: 1,
+            found: None
+        }
+    );
+    check_err!(
+        ".",
+        NoDigits {
+            position: 1,
+            found: None
+        }
+    );
+    check_err!(
+        "-.e5",
+        NoDigits {
+            position: 2,
+            found: Some('e')
+        }
+    );
+    check_err!(
+        "e5",
+        NoDigits {
+            position: 0,
+            found: Some('e')
+        }
+    );
+    check_err!(
+        "in",
+        NoDigits {
+            position: 0,
+            found: Some('i')
+        }
+    );
+    check_err!(
+        "-\u{221e}",
+        NoDigits {
+            position: 1,
+            found: Some('\u{221e}')
+        }
+    );
+    check_err!(
+        &b"\xff1"[..],
+        NoDigits {
+            position: 0,
+            found: Some('\u{fffd}')
+        }
+    );
+    let opts = ParseOptions::new().allow_leading_point(false);
+    check_err!(
+        &opts,
+        ".5",
+        NoDigits {
+            position: 0,
+            found: Some('.')
+        }
+    );
+    let opts = ParseOptions::new().allow_special_values(false);
+    check_err!(
+        &opts,
+        "-inf",
+        NoDigits {
+            position: 1,
+            found: Some('i')
+        }
+    );
+    let opts = ParseOptions::new().whitespace(Whitespace::Unicode);
+    check_err!(
+        &opts,
+        "\u{a0}x",
+        NoDigits {
+            position: 2,
+            found: Some('x')
+        }
+    );
+}
+
+#[test]
+fn test_error_exponent() {
+    check_err!(
+        "1e",
+        InvalidExponent {
+            position: 2,
+            found: None
+        }
+    );
+    check_err!(
+        "1E+",
+        InvalidExponent {
+            position: 3,
+            found: None
+        }
+    );
+    check_err!(
+        "1.5e-x",
+        InvalidExponent {
+            position: 5,
+            found: Some('x')
+        }
+    );
+    check_err!(
+        ".5ee",
+        InvalidExponent {
+            position: 3,
+            found: Some('e')
+        }
+    );
+    check_err!(
+        "1.e",
+        InvalidExponent {
+            position: 3,
+            found: None
+        }
+    );
+    let opts = ParseOptions::fortran();
+    check_err!(
+        &opts,
+        "1.5D",
+        InvalidExponent {
+            position: 4,
+            found: None
+        }
+    );
+    check_err!(
+        &opts,
+        "1.5-",
+        InvalidExponent {
+            position: 4,
+            found: None
+        }
+    );
+    check_err!(
+        &opts,
+        "1.5+a",
+        InvalidExponent {
+            position: 4,
+            found: Some('a')
+        }
+    );
+}
+
+#[test]
+fn test_error_trailing() {
+    check_err!(
+        "1.5x",
+        TrailingCharacters {
+            consumed: 3,
+            found: 'x'
+        }
+    );
+    check_err!(
+        "1.5.",
+        TrailingCharacters {
+            consumed: 3,
+            found: '.'
+        }
+    );
+    check_err!(
+        "1e5e",
+        TrailingCharacters {
+            consumed: 3,
+            found: 'e'
+        }
+    );
+    check_err!(
+        "1e5e5",
+        TrailingCharacters {
+            consumed: 3,
+            found: 'e'
+        }
+    );
+    check_err!(
+        "1.5-",
+        TrailingCharacters {
+            consumed: 3,
+            found: '-'
+        }
+    );
+    check_err!(
+        "1 ",
+        TrailingCharacters {
+            consumed: 1,
+            found: ' '
+        }
+    );
+    check_err!(
+        "infe",
+        TrailingCharacters {
+            consumed: 3,
+            found: 'e'
+        }
+    );
+    check_err!(
+        "nan(1)",
+        TrailingCharacters {
+            consumed: 3,
+            found: '('
+        }
+    );
+    check_err!(
+        "1\u{b0}",
+        TrailingCharacters {
+            consumed: 1,
+            found: '\u{b0}'
+        }
+    );
+    let opts = ParseOptions::new().whitespace(Whitespace::Ascii);
+    check_err!(
+        &opts,
+        " 1 e5",
+        TrailingCharacters {
+            consumed: 3,
+            found: 'e'
+        }
+    );
+    let opts = ParseOptions::new().allow_leading_zeros(false);
+    check_err!(
+        &opts,
+        "01",
+        TrailingCharacters {
+            consumed: 1,
+            found: '1'
+        }
+    );
+    let opts = ParseOptions::new().digit_separator(DigitSeparator::new(b'_'));
+    check_err!(
+        &opts,
+        "1__0",
+        TrailingCharacters {
+            consumed: 1,
+            found: '_'
+        }
+    );
+    check_err!(
+        &opts,
+        "1_e5",
+        TrailingCharacters {
+            consumed: 1,
+            found: '_'
+        }
+    );
+}
+
+#[test]
+fn test_error_ok() {
+    assert_eq!(parse_detailed::<f64, _>("1.5e3"), Ok(1500.));
+    assert_eq!(parse_partial_detailed::<f64, _>("1.5e3x"), Ok((1500., 5)));
+    assert_eq!(parse_partial_detailed::<f32, _>("1ex"), Ok((1., 1)));
+    let opts = ParseOptions::new().whitespace(Whitespace::Ascii);
+    assert_eq!(parse_detailed_with_options::<f64, _>(" 1 ", &opts), Ok(1.));
+    let _: fast_float::Error = ParseError::Empty.into();
+}
+
+#[test]
+fn test_error_display() {
+    let check = |s: &str, msg: &str| {
+        let e = parse_detailed::<f64, _>(s).unwrap_err();
+        assert_eq!(e.to_string(), msg);
+    };
+    check("", "cannot parse float from empty string");
+    check("+-1", "invalid sign '-' at position 1");
+    check("-", "expected a digit at position 1, found end of input");
+    check("\n", "expected a digit at position 0, found '\\n'");
+    check(
+        "1e+x",
+        "expected an exponent digit at position 3, found 'x'",
+    );
+    check(
+        "1é",
+        "unexpected character 'é' after the number at position 1",
+    );
+    let e = parse_detailed::<f64, _>("1.5 kg").unwrap_err();
+    assert_eq!(e.position(), 3);
+    assert_eq!(ParseError::Empty.position(), 0);
+}
@@ -0,0 +1,150 @@
+#![allow(clippy::excessive_precision)]
+
+use core::cmp::Ordering;
+
+use fast_float::{
+    parse_partial_with_exactness, parse_with_exactness, Exactness, FastFloat, ParseOptions,
+    Whitespace,
+};
+use num_bigint::BigUint;
+
+use Exactness::{Exact, RoundedDown, RoundedUp};
+
+macro_rules! check {
+    ($ty:ty, $s:expr, $exactness:expr) => {
+        let s = $s;
+        let options = ParseOptions::new();
+        let (x, exactness) = parse_with_exactness::<$ty, _>(s, &options).unwrap();
+        assert_eq!(x.to_bits(), s.parse::<$ty>().unwrap().to_bits(), "{:?}", s);
+        assert_eq!(exactness, $exactness, "{:?}", s);
+    };
+}
+
+#[test]
+fn test_exactness_f64() {
+    check!(f64, "0", Exact);
+    check!(f64, "-0.0e-999", Exact);
+    check!(f64, "1", Exact);
+    check!(f64, "0.375", Exact);
+    check!(f64, "-1.5e3", Exact);
+    check!(f64, "1e22", Exact);
+    check!(f64, "1e23", RoundedDown);
+    check!(f64, "0.1", RoundedUp);
+    check!(f64, "-0.1", RoundedDown);
+    check!(f64, "0.2", RoundedUp);
+    check!(f64, "0.3", RoundedDown);
+    check!(f64, "9007199254740992", Exact);
+    check!(f64, "9007199254740993", RoundedDown);
+    check!(f64, "9007199254740995", RoundedUp);
+    check!(f64, "9007199254740993.0000000000000000000000001", RoundedUp);
+    check!(f64, "18446744073709551616", Exact);
+    check!(f64, "1.7976931348623157e308", RoundedUp);
+    check!(f64, "179769313486231570814527423731704356798070567525844996598917476803157260780028538760589558632766878171540458953514382464234321326889464182768467546703537516986049910576551282076245490090389328944075868508455133942304583236903222948165808559332123348274797826204144723168738177180919299881250404026184124858368", Exact);
+    check!(f64, "1e400", RoundedUp);
+    check!(f64, "-1e400", RoundedDown);
+    check!(f64, "5e-324", RoundedDown);
+    check!(f64, "4.9406564584124654e-324", RoundedUp);
+    check!(f64, "1e-400", RoundedDown);
+    check!(f64, "-1e-400", RoundedUp);
+    check!(f64, "2.2250738585072014e-308", RoundedDown);
+    check!(f64, "inf", Exact);
+    check!(f64, "-infinity", Exact);
+    check!(f64, "NaN", Exact);
+}
+
+#[test]
+fn test_exactness_f32() {
+    check!(f32, "0.5", Exact);
+    check!(f32, "16777216", Exact);
+    check!(f32, "16777217", RoundedDown);
+    check!(f32, "16777219", RoundedUp);
+    check!(f32, "0.1", RoundedUp);
+    check!(f32, "0.2", RoundedUp);
+    check!(f32, "0.3", RoundedUp);
+    check!(f32, "-0.3", RoundedDown);
+    check!(f32, "3.4028235e38", RoundedDown);
+    check!(f32, "1e39", RoundedUp);
+    check!(f32, "1e-46", RoundedDown);
+    check!(f32, "1.40129846e-45", RoundedUp);
+    check!(f32, "inf", Exact);
+}
+
+#[test]
+fn test_exactness_partial() {
+    let options = ParseOptions::new().whitespace(Whitespace::Ascii);
+    let parse = |s| parse_partial_with_exactness::<f64, _>(s, &options).unwrap();
+    assert_eq!(parse("  0.1 "), (0.1, 6, RoundedUp));
+    assert_eq!(parse("0.5x"), (0.5, 3, Exact));
+    assert_eq!(parse("1e23e"), (1e23, 4, RoundedDown));
+    assert_eq!(parse(" -0.3,0.3"), (-0.3, 5, RoundedUp));
+    assert_eq!(parse("infx").2, Exact);
+    assert!(parse_with_exactness::<f64, _>("0.1x", &options).is_err());
+    assert!(f64::parse_float_with_exactness("", &options).is_err());
+}
+
+// exact comparison of the magnitude of the float against the decimal via big integers
+fn compare(bits: u64, mantissa_bits: u32, min_exponent: i32, digits: &str, exp10: i32) -> Ordering {
+    let power2 = (bits >> mantissa_bits) as i32;
+    let mut mantissa = bits & ((1_u64 << mantissa_bits) - 1);
+    if power2 != 0 {
+        mantissa |= 1_u64 << mantissa_bits;
+    }
+    let exp2 = power2.max(1) + min_exponent - mantissa_bits as i32;
+    let (mut lhs, mut rhs) = (BigUint::from(mantissa), digits.parse::<BigUint>().unwrap());
+    if exp2 > 0 {
+        lhs <<= exp2 as usize;
+    } else {
+        rhs <<= (-exp2) as usize;
+    }
+    if exp10 > 0 {
+        rhs *= BigUint::from(10_u32).pow(exp10 as u32);
+    } else {
+        lhs *= BigUint::from(10_u32).pow((-exp10) as u32);
+    }
+    lhs.cmp(&rhs)
+}
+
+fn expected(ord: Ordering, negative: bool) -> Exactness {
+    match (ord, negative) {
+        (Ordering::Equal, _) => Exact,
+        (Ordering::Greater, false) | (Ordering::Less, true) => RoundedUp,
+        _ => RoundedDown,
+    }
+}
+
+#[test]
+fn test_exactness_random() {
+    let rng = fastrand::Rng::with_seed(0);
+    let options = ParseOptions::new();
+    for _ in 0..20_000 {
+        let len = rng.usize(1..30);
+        let digits: String = (0..len).map(|_| rng.char('0'..='9')).collect();
+        let digits = digits.trim_start_matches('0');
+        if digits.is_empty() {
+            continue;
+        }
+        let negative = rng.bool();
+        let exp = rng.i32(-360..330);
+        let s = format!("{}{}e{}", if negative { "-" } else { "" }, digits, exp);
+
+        let (x, exactness) = parse_with_exactness::<f64, _>(&s, &options).unwrap();
+        let bits = x.abs().to_bits();
+        let ord = if x.is_infinite() {
+            Ordering::Greater
+        } else {
+            compare(bits, 52, -1023, digits, exp)
+        };
+        assert_eq!(exactness, expected(ord, negative), "{:?}", s);
+
+        if (-60..50).contains(&exp) {
+            let (x, exactness) = parse_with_exactness::<f32, _>(&s, &options).unwrap();
+            let bits = u64::from(x.abs().to_bits());
+            let ord = if x.is_infinite() {
+                Ordering::Greater
+            } else {
+                compare(bits, 23, -127, digits, exp)
+            };
+            assert_eq!(exactness, expected(ord, negative), "{:?}", s);
+        }
+    }
+}
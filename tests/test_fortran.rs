@@ -0,0 +1,130 @@
+#![allow(clippy::excessive_precision)]
+
+use fast_float::{parse_fortran_field, FastFloat};
+
+macro_rules! check_ok {
+    ($s:expr, $w:expr, $d:expr, $x:expr) => {
+        check_ok!($s, $w, $d, $x, $s.len().min($w));
+    };
+    ($s:expr, $w:expr, $d:expr, $x:expr, $n:expr) => {
+        let s = $s;
+        let (x, n) = parse_fortran_field::<f64, _>(s, $w, $d).unwrap();
+        assert_eq!((x.to_bits(), n), (($x as f64).to_bits(), $n), "{:?}", s);
+        let (x, n) = f32::parse_fortran_field(s.as_bytes(), $w, $d).unwrap();
+        assert_eq!((x, n), ($x as f32, $n), "{:?}", s);
+    };
+}
+
+macro_rules! check_err {
+    ($s:expr, $w:expr, $d:expr) => {
+        let s = $s;
+        assert!(parse_fortran_field::<f64, _>(s, $w, $d).is_err(), "{:?}", s);
+        assert!(parse_fortran_field::<f32, _>(s, $w, $d).is_err(), "{:?}", s);
+    };
+}
+
+#[test]
+fn test_fortran_implied_point() {
+    check_ok!("12345", 10, 3, 12.345);
+    check_ok!("     12345", 10, 3, 12.345);
+    check_ok!("-12345", 10, 0, -12345.);
+    check_ok!("+5", 10, 3, 0.005);
+    check_ok!("1", 10, 20, 1e-20);
+    check_ok!("00012345", 8, 8, 0.00012345);
+    check_ok!("12345E2", 10, 3, 1234.5);
+    check_ok!("12345D-2", 10, 3, 0.12345);
+    check_ok!("12345-2", 10, 3, 0.12345);
+    check_ok!("12345+02", 10, 3, 1234.5);
+    check_ok!("1", 5, 400, 0.);
+    check_ok!("1", 5, 1 << 40, 0.);
+}
+
+#[test]
+fn test_fortran_explicit_point() {
+    check_ok!("1.5", 10, 3, 1.5);
+    check_ok!("   -1.5D+02", 11, 3, -150.);
+    check_ok!("123.", 10, 3, 123.);
+    check_ok!(".123", 10, 0, 0.123);
+    check_ok!("1.234-100", 10, 5, 1.234e-100);
+    check_ok!("1.5Q3", 10, 1, 1.5e3);
+}
+
+#[test]
+fn test_fortran_blanks() {
+    check_ok!("", 10, 3, 0., 0);
+    check_ok!("          ", 10, 3, 0.);
+    check_ok!(" 1 2 3 4 5", 10, 3, 12.345);
+    check_ok!("- 12 345  ", 10, 3, -12.345);
+    check_ok!("1 . 5     ", 10, 3, 1.5);
+    check_ok!("12345 E 2 ", 10, 3, 1234.5);
+    check_ok!("12345E- 2", 10, 3, 0.12345);
+}
+
+#[test]
+fn test_fortran_record() {
+    let record = "  1234567-1.0E+00    42";
+    let (x, n) = parse_fortran_field::<f64, _>(record, 5, 2).unwrap();
+    assert_eq!((x, n), (1.23, 5));
+    let (x, n) = parse_fortran_field::<f64, _>(&record[5..], 4, 4).unwrap();
+    assert_eq!((x, n), (0.4567, 4));
+    let (x, n) = parse_fortran_field::<f64, _>(&record[9..], 8, 4).unwrap();
+    assert_eq!((x, n), (-1., 8));
+    // the record is shorter than the field
+    let (x, n) = parse_fortran_field::<f64, _>(&record[17..], 10, 1).unwrap();
+    assert_eq!((x, n), (4.2, 6));
+    // the field is narrower than the number
+    check_ok!("12345", 3, 1, 12.3);
+    check_ok!("1.5E+10", 6, 0, 15.);
+}
+
+#[test]
+fn test_fortran_special() {
+    check_ok!("  Infinity", 10, 3, (1. / 0.));
+    check_ok!("-inf", 10, 3, -(1. / 0.));
+    assert!(parse_fortran_field::<f64, _>(" NaN", 4, 2)
+        .unwrap()
+        .0
+        .is_nan());
+}
+
+#[test]
+fn test_fortran_errors() {
+    check_err!("1,5", 10, 3);
+    check_err!("1.5.", 10, 3);
+    check_err!("+", 10, 3);
+    check_err!("-   ", 10, 3);
+    check_err!("E5", 10, 3);
+    check_err!("12345E", 10, 3);
+    check_err!("1.5x", 10, 3);
+    check_err!("\t1.5", 10, 3);
+    check_err!("1.5E+10", 5, 0);
+}
+
+#[test]
+fn test_fortran_random() {
+    // the implied point must round exactly like an explicit exponent does
+    let rng = fastrand::Rng::with_seed(0);
+    for _ in 0..10_000 {
+        let len = rng.usize(1..40);
+        let digits: String = (0..len).map(|_| rng.char('0'..='9')).collect();
+        let d = rng.usize(..340);
+        let exp = rng.i32(-30..30);
+        let s = format!("{}E{}", digits, exp);
+        let expected: f64 = format!("{}e{}", digits, exp as i64 - d as i64)
+            .parse()
+            .unwrap();
+        let (x, n) = parse_fortran_field::<f64, _>(&s, 50, d).unwrap();
+        assert_eq!(
+            (x.to_bits(), n),
+            (expected.to_bits(), s.len()),
+            "{:?} {}",
+            s,
+            d
+        );
+        let expected: f32 = format!("{}e{}", digits, exp as i64 - d as i64)
+            .parse()
+            .unwrap();
+        let (x, _) = parse_fortran_field::<f32, _>(&s, 50, d).unwrap();
+        assert_eq!(x.to_bits(), expected.to_bits(), "{:?} {}", s, d);
+    }
+}
@@ -0,0 +1,189 @@
+#![allow(clippy::legacy_numeric_constants)]
+
+use fast_float::{parse_go, FastFloat, GoError};
+
+macro_rules! check_ok {
+    ($s:expr, $x:expr) => {
+        let s = $s;
+        assert_eq!(
+            parse_go::<f64, _>(s).unwrap().to_bits(),
+            ($x as f64).to_bits(),
+            "{:?}",
+            s
+        );
+        assert_eq!(
+            f32::parse_float_go(s.as_bytes()).unwrap(),
+            $x as f32,
+            "{:?}",
+            s
+        );
+    };
+}
+
+macro_rules! check_err {
+    ($s:expr) => {
+        let s = $s;
+        assert_eq!(parse_go::<f64, _>(s), Err(GoError::Syntax), "{:?}", s);
+        assert_eq!(parse_go::<f32, _>(s), Err(GoError::Syntax), "{:?}", s);
+    };
+}
+
+#[test]
+fn test_go_decimal() {
+    // a selection of the test cases from Go's strconv/atof_test.go
+    check_ok!("1", 1.);
+    check_ok!("+1", 1.);
+    check_ok!("1e23", 1e23);
+    check_ok!("1E23", 1e23);
+    check_ok!("100000000000000000000000", 1e23);
+    check_ok!("1e-100", 1e-100);
+    check_ok!("123456700", 1.234_567e8);
+    check_ok!("-1", -1.);
+    check_ok!("-0.1", -0.1);
+    check_ok!("-0", -0.);
+    check_ok!("1e-20", 1e-20);
+    check_ok!("625e-3", 0.625);
+    check_ok!(".5", 0.5);
+    check_ok!("1.", 1.);
+    check_ok!("0e+01234567890123456789", 0.);
+    check_ok!("1e-350", 0.);
+    check_ok!("-1e-350", -0.);
+    check_ok!("0.000000000000000000000000000000000000000000001e50", 1e5);
+    check_err!("");
+    check_err!("1x");
+    check_err!("1.1.");
+    check_err!("1e");
+    check_err!("1e-");
+    check_err!(".e-1");
+    check_err!("0x");
+    check_err!("0x1");
+    check_err!(" 1");
+    check_err!("1 ");
+}
+
+#[test]
+fn test_go_hex() {
+    check_ok!("0x1p0", 1.);
+    check_ok!("0x1p1", 2.);
+    check_ok!("0x1p-1", 0.5);
+    check_ok!("0x1ep-1", 15.);
+    check_ok!("-0x1ep-1", -15.);
+    check_ok!("0x1.8p1", 3.);
+    check_ok!("0X.8P1", 1.);
+    check_ok!("0x1.p1", 2.);
+    check_ok!(
+        "0x1fffffffffffffp-52",
+        9_007_199_254_740_991. / 4_503_599_627_370_496.
+    );
+    check_ok!("0x1p-1074", 5e-324);
+    check_err!("0x1");
+    check_err!("0x1.8");
+    check_err!("0x1e");
+    check_err!("0xp1");
+    check_err!("0x.p1");
+    check_err!("0x1p");
+    check_err!("0x1p+");
+    check_err!("0x1g1");
+    check_err!("0b1p0");
+    check_err!("0o1p0");
+}
+
+#[test]
+fn test_go_underscores() {
+    check_ok!("1_23.50_0_0e+1_2", 1.235e14);
+    check_ok!("0x_1_2.3_4_5p+1_2", 74565.);
+    check_ok!("-0x_1p0", -1.);
+    check_err!("-_123.5e+12");
+    check_err!("+_123.5e+12");
+    check_err!("_123.5e+12");
+    check_err!("1__23.5e+12");
+    check_err!("123_.5e+12");
+    check_err!("123._5e+12");
+    check_err!("123.5_e+12");
+    check_err!("123.5__0e+12");
+    check_err!("123.5e_+12");
+    check_err!("123.5e+_12");
+    check_err!("123.5e_-12");
+    check_err!("123.5e-_12");
+    check_err!("123.5e+1__2");
+    check_err!("123.5e+12_");
+    check_err!("0x_1_2.3_4_5p+1__2");
+    check_err!("0x_1_2.3_4_5p+1_2_");
+    check_err!("0x__1p0");
+    check_err!("0_x1p0");
+    check_err!("0x1_p0");
+    check_err!("0x1p_0");
+    check_err!("0x_.8p0");
+    check_err!("inf_");
+}
+
+#[test]
+fn test_go_special() {
+    for &s in &["inf", "+Inf", "INF", "infinity", "+Infinity", "iNfInItY"] {
+        assert_eq!(parse_go::<f64, _>(s), Ok(core::f64::INFINITY), "{:?}", s);
+    }
+    for &s in &["-inf", "-Inf", "-INFINITY"] {
+        assert_eq!(
+            parse_go::<f32, _>(s),
+            Ok(core::f32::NEG_INFINITY),
+            "{:?}",
+            s
+        );
+    }
+    for &s in &["nan", "NaN", "NAN"] {
+        assert!(parse_go::<f64, _>(s).unwrap().is_nan());
+    }
+    check_err!("+nan");
+    check_err!("-NaN");
+    check_err!("infinit");
+    check_err!("infinityy");
+    check_err!("in");
+    check_err!("nan(1)");
+    check_err!("++inf");
+}
+
+#[test]
+fn test_go_range() {
+    let inf = core::f64::INFINITY;
+    assert_eq!(parse_go::<f64, _>("1e309"), Err(GoError::Range(inf)));
+    assert_eq!(parse_go::<f64, _>("-1e309"), Err(GoError::Range(-inf)));
+    assert_eq!(
+        parse_go::<f64, _>("1e+10000000000"),
+        Err(GoError::Range(inf))
+    );
+    assert_eq!(parse_go::<f64, _>("0x1p1024"), Err(GoError::Range(inf)));
+    assert_eq!(
+        parse_go::<f64, _>("0x1.fffffffffffff7fffp1023"),
+        Ok(core::f64::MAX)
+    );
+    assert_eq!(
+        parse_go::<f64, _>("0x1.fffffffffffff8p1023"),
+        Err(GoError::Range(inf))
+    );
+    assert_eq!(
+        parse_go::<f64, _>("1.7976931348623158e308"),
+        Ok(core::f64::MAX)
+    );
+    assert_eq!(
+        parse_go::<f64, _>("1.7976931348623159e308"),
+        Err(GoError::Range(inf))
+    );
+    assert_eq!(parse_go::<f32, _>("3.4028234e38"), Ok(core::f32::MAX));
+    assert_eq!(
+        parse_go::<f32, _>("-3.4028236e38"),
+        Err(GoError::Range(core::f32::NEG_INFINITY))
+    );
+    assert_eq!(parse_go::<f64, _>("1e-400"), Ok(0.));
+    assert_eq!(parse_go::<f64, _>("0x1p-1100"), Ok(0.));
+    assert_eq!(parse_go::<f64, _>("0e99999"), Ok(0.));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_go_error_display() {
+    assert_eq!(GoError::<f64>::Syntax.to_string(), "invalid syntax");
+    assert_eq!(
+        GoError::Range(core::f64::INFINITY).to_string(),
+        "value out of range"
+    );
+}
@@ -0,0 +1,179 @@
+#![allow(clippy::legacy_numeric_constants)]
+
+use fast_float::{parse_hex, parse_hex_partial, FastFloat};
+use hexf_parse::{parse_hexf32, parse_hexf64};
+
+macro_rules! check_ok {
+    ($s:expr) => {
+        let s = $s;
+        if let Ok(x) = parse_hexf32(s, false) {
+            assert_eq!(parse_hex::<f32, _>(s).unwrap(), x);
+        }
+        assert_eq!(
+            parse_hex::<f64, _>(s).unwrap(),
+            parse_hexf64(s, false).unwrap()
+        );
+        assert_eq!(f64::parse_hex_float_partial(s).unwrap().1, s.len());
+    };
+    ($s:expr, $x:expr, $ty:ty) => {
+        let s = $s;
+        assert_eq!(
+            parse_hex::<$ty, _>(s).unwrap().to_bits(),
+            ($x as $ty).to_bits()
+        );
+        assert_eq!(<$ty>::parse_hex_float(s.as_bytes()).unwrap(), $x);
+        assert_eq!(parse_hex_partial::<$ty, _>(s).unwrap(), ($x, s.len()));
+    };
+}
+
+macro_rules! check_ok_partial {
+    ($s:expr, $x:expr, $n:expr) => {
+        let s = $s;
+        assert!(parse_hex::<f64, _>(s).is_err());
+        assert_eq!(parse_hex_partial::<f64, _>(s).unwrap(), ($x, $n));
+        assert!(parse_hex::<f32, _>(s).is_err());
+        assert_eq!(parse_hex_partial::<f32, _>(s).unwrap(), ($x, $n));
+    };
+}
+
+macro_rules! check_err {
+    ($s:expr) => {
+        let s = $s;
+        assert!(parse_hex::<f64, _>(s).is_err());
+        assert!(parse_hex_partial::<f64, _>(s).is_err());
+        assert!(parse_hex::<f32, _>(s).is_err());
+        assert!(parse_hex_partial::<f32, _>(s).is_err());
+    };
+}
+
+fn format_hex(x: f64) -> String {
+    let bits = x.to_bits();
+    let sign = if bits >> 63 != 0 { "-" } else { "" };
+    let exponent = ((bits >> 52) & 0x7FF) as i64;
+    let mantissa = bits & ((1 << 52) - 1);
+    if exponent == 0 {
+        format!("{}0x0.{:013x}p-1022", sign, mantissa)
+    } else {
+        format!("{}0x1.{:013x}p{:+}", sign, mantissa, exponent - 1023)
+    }
+}
+
+#[test]
+fn test_hex_basic() {
+    check_ok!("0x1.921fb54442d18p+1", core::f64::consts::PI, f64);
+    check_ok!("0x1.8p3", 12., f64);
+    check_ok!("-0X1.8P3", -12., f32);
+    check_ok!("+0xAbC", 2748., f64);
+    check_ok!("0x.8", 0.5, f64);
+    check_ok!("0x1.", 1., f32);
+    check_ok!("0x0p0", 0., f64);
+    check_ok!("-0x0", -0., f64);
+    check_ok!("0x1p-2", 0.25, f32);
+    check_ok!("0x1.fffffffffffffp1023");
+    check_ok!("0x1.fffffep127");
+    check_ok!("0x0.0000000000001p-1022");
+    check_ok!("0x1.0p-149");
+    check_ok!("0x00000000000000000000001.000000000000000000000p0", 1., f64);
+    check_ok!(
+        "0x0.000000000000000000000000000000000000000001p+168",
+        1.,
+        f32
+    );
+    check_ok!(
+        "0x123456789abcdef0123456789abcdef0p-124",
+        1.137_777_777_777_777_8,
+        f64
+    );
+}
+
+#[test]
+fn test_hex_partial() {
+    check_ok_partial!("0x1.8p3foo", 12., 7);
+    check_ok_partial!("0x1p", 1., 3);
+    check_ok_partial!("0x1p+", 1., 3);
+    check_ok_partial!("0x1e1.8px", 481.5, 7);
+    check_ok_partial!("0x1.8.1", 1.5, 5);
+    check_err!("");
+    check_err!("0");
+    check_err!("0x");
+    check_err!("0x.");
+    check_err!("0x.p1");
+    check_err!("x1");
+    check_err!("1.5");
+    check_err!("--0x1");
+    check_err!(" 0x1");
+}
+
+#[test]
+fn test_hex_rounding() {
+    // ties to even and sticky bits beyond the 64-bit mantissa
+    check_ok!("0x1.00000000000008p0", 1., f64);
+    check_ok!("0x1.00000000000018p0", 1.000_000_000_000_000_4, f64);
+    check_ok!(
+        "0x1.000000000000080000000000000000001p0",
+        1.000_000_000_000_000_2,
+        f64
+    );
+    check_ok!("0x1.000000000000080000000000000000000p0", 1., f64);
+    check_ok!("0x1.000001p0", 1., f32);
+    check_ok!("0x1.0000010000000000000000000001p0", 1.000_000_1, f32);
+    check_ok!("0x1.000003p0", 1.000_000_2, f32);
+    check_ok!(
+        "0x1.fffffffffffff7ffffffffffp0",
+        1.999_999_999_999_999_8,
+        f64
+    );
+    check_ok!("0x1.fffffffffffff8p0", 2., f64);
+}
+
+#[test]
+fn test_hex_subnormal_and_overflow() {
+    check_ok!("0x1p-1074", 5e-324, f64);
+    check_ok!("0x1p-1075", 0., f64);
+    check_ok!("0x1.0000000000001p-1075", 5e-324, f64);
+    check_ok!("0x1.8p-1075", 5e-324, f64);
+    check_ok!("0x1.8p-1074", 1e-323, f64);
+    check_ok!("0x1.4p-1073", 1e-323, f64);
+    check_ok!("0x1p-1076", 0., f64);
+    check_ok!("0x1.ffffffffffffep-1023", 2.225_073_858_507_201e-308, f64);
+    check_ok!("0x1.fffffffffffffp-1023", 2.225_073_858_507_201_4e-308, f64);
+    check_ok!(
+        "0x1.fffffffffffff8p-1023",
+        2.225_073_858_507_201_4e-308,
+        f64
+    );
+    check_ok!("0x1p-150", 0., f32);
+    check_ok!("0x1.000002p-150", 1e-45, f32);
+    check_ok!("0x1p-99999", 0., f64);
+    check_ok!("0x1.fffffffffffff7fp1023", core::f64::MAX, f64);
+    check_ok!("0x1.fffffffffffff8p1023", core::f64::INFINITY, f64);
+    check_ok!("0x1p1024", core::f64::INFINITY, f64);
+    check_ok!("-0x1p99999", core::f64::NEG_INFINITY, f64);
+    check_ok!("0x1.fffffefp127", core::f32::MAX, f32);
+    check_ok!("0x1.ffffffp127", core::f32::INFINITY, f32);
+}
+
+#[test]
+fn test_hex_roundtrip_random() {
+    let rng = fastrand::Rng::with_seed(0);
+    for _ in 0..100_000 {
+        let x = f64::from_bits(rng.u64(..));
+        if !x.is_finite() {
+            continue;
+        }
+        let s = format_hex(x);
+        assert_eq!(
+            parse_hex::<f64, _>(&s).unwrap().to_bits(),
+            x.to_bits(),
+            "{}",
+            s
+        );
+        // the input is exact, so the correctly rounded f32 is the same as the cast
+        assert_eq!(
+            parse_hex::<f32, _>(&s).unwrap().to_bits(),
+            (x as f32).to_bits(),
+            "{}",
+            s
+        );
+    }
+}
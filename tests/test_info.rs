@@ -0,0 +1,182 @@
+#![allow(clippy::legacy_numeric_constants)]
+
+use fast_float::{
+    parse_partial_with_info, parse_with_info, DigitSeparator, FastFloat, NumberInfo, ParseOptions,
+    SpecialTokens, SpecialValue, Whitespace,
+};
+
+fn info(s: &str, options: &ParseOptions) -> NumberInfo {
+    let (x, info) = parse_with_info::<f64, _>(s, options).unwrap();
+    let (y, n, other) = f32::parse_float_partial_with_info(s, options).unwrap();
+    assert_eq!(n, s.len(), "{:?}", s);
+    assert_eq!(info, other, "{:?}", s);
+    if !x.is_nan() {
+        assert_eq!(
+            x,
+            fast_float::parse_with_options::<f64, _>(s, options).unwrap()
+        );
+        assert_eq!(
+            y,
+            fast_float::parse_with_options::<f32, _>(s, options).unwrap()
+        );
+    }
+    info
+}
+
+macro_rules! check {
+    ($s:expr, $negative:expr, $digits:expr, $point:expr, $exp:expr, $ranges:expr) => {
+        check!(
+            $s,
+            ParseOptions::new(),
+            $negative,
+            $digits,
+            $point,
+            $exp,
+            $ranges
+        );
+    };
+    ($s:expr, $options:expr, $negative:expr, $digits:expr, $point:expr, $exp:expr, $ranges:expr) => {
+        let (s, options) = ($s, $options);
+        let info = info(s, &options);
+        let exp: Option<i64> = $exp;
+        assert!(!info.is_special(), "{:?}", s);
+        assert_eq!(info.is_negative(), $negative, "{:?}", s);
+        assert_eq!(info.significant_digits(), $digits, "{:?}", s);
+        assert_eq!(info.has_decimal_point(), $point, "{:?}", s);
+        assert_eq!(info.has_exponent(), exp.is_some(), "{:?}", s);
+        assert_eq!(info.exponent(), exp.unwrap_or(0), "{:?}", s);
+        assert_eq!(
+            (
+                info.integer_range(),
+                info.fraction_range(),
+                info.exponent_range()
+            ),
+            $ranges,
+            "{:?}",
+            s
+        );
+    };
+}
+
+#[test]
+fn test_info_basic() {
+    check!("0", false, 0, false, None, (0..1, 1..1, 1..1));
+    check!("-0", true, 0, false, None, (1..2, 2..2, 2..2));
+    check!("123", false, 3, false, None, (0..3, 3..3, 3..3));
+    check!("100", false, 3, false, None, (0..3, 3..3, 3..3));
+    check!("-1.5", true, 2, true, None, (1..2, 3..4, 4..4));
+    check!("0.0120", false, 3, true, None, (0..1, 2..6, 6..6));
+    check!("1e5", false, 1, false, Some(5), (0..1, 1..1, 2..3));
+    check!("1.E-05", false, 1, true, Some(-5), (0..1, 2..2, 3..6));
+    check!(".5e+10", false, 1, true, Some(10), (0..0, 1..2, 3..6));
+    check!(
+        "12345678901234567890.123456789e-20",
+        false,
+        29,
+        true,
+        Some(-20),
+        (0..20, 21..30, 31..34)
+    );
+    check!(
+        "1e99999999999999999999",
+        false,
+        1,
+        false,
+        Some(i64::max_value()),
+        (0..1, 1..1, 2..22)
+    );
+    check!(
+        "1e-99999999999999999999",
+        false,
+        1,
+        false,
+        Some(i64::min_value()),
+        (0..1, 1..1, 2..23)
+    );
+}
+
+#[test]
+fn test_info_options() {
+    let options = ParseOptions::new()
+        .digit_separator(DigitSeparator::new(b'_').exponent(true))
+        .allow_leading_plus(true);
+    check!(
+        "+1_000.000_5e1_0",
+        options,
+        false,
+        8,
+        true,
+        Some(10),
+        (1..6, 7..12, 13..16)
+    );
+    check!(
+        "1,5",
+        ParseOptions::new().decimal_point(b','),
+        false,
+        2,
+        true,
+        None,
+        (0..1, 2..3, 3..3)
+    );
+    check!(
+        "1.234-100",
+        ParseOptions::fortran(),
+        false,
+        4,
+        true,
+        Some(-100),
+        (0..1, 2..5, 5..9)
+    );
+    check!(
+        "-1.0D+05",
+        ParseOptions::fortran(),
+        true,
+        2,
+        true,
+        Some(5),
+        (1..2, 3..4, 5..8)
+    );
+    check!(
+        " \t-2.5e3 ",
+        ParseOptions::new().whitespace(Whitespace::Ascii),
+        true,
+        2,
+        true,
+        Some(3),
+        (3..4, 5..6, 7..8)
+    );
+}
+
+#[test]
+fn test_info_special() {
+    const TOKENS: SpecialTokens = SpecialTokens::new(&[
+        ("1.#INF", SpecialValue::Infinity),
+        ("-1.#INF", SpecialValue::NegInfinity),
+    ]);
+    let options = ParseOptions::new();
+    for &s in &["inf", "-infinity", "NaN", "+nan"] {
+        let info = info(s, &options);
+        assert!(info.is_special(), "{:?}", s);
+        assert_eq!(info.is_negative(), s.starts_with('-'), "{:?}", s);
+        assert_eq!(info.significant_digits(), 0);
+        assert!(!info.has_decimal_point() && !info.has_exponent());
+        assert_eq!(info.integer_range(), 0..0);
+    }
+    let options = ParseOptions::new().special_tokens(TOKENS);
+    assert!(info("-1.#INF", &options).is_special());
+    assert!(info("1.#INF", &options).is_special());
+    assert!(!info("1.5", &options).is_special());
+}
+
+#[test]
+fn test_info_partial() {
+    let options = ParseOptions::new();
+    let (x, n, info) = parse_partial_with_info::<f64, _>("1.5e3x", &options).unwrap();
+    assert_eq!((x, n), (1500., 5));
+    assert_eq!(info.exponent_range(), 4..5);
+    let (_, n, info) = parse_partial_with_info::<f64, _>("1.5ex", &options).unwrap();
+    assert_eq!(n, 3);
+    assert!(!info.has_exponent());
+    assert!(parse_with_info::<f64, _>("1.5x", &options).is_err());
+    assert!(parse_with_info::<f64, _>("", &options).is_err());
+}
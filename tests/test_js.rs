@@ -0,0 +1,303 @@
+#![allow(clippy::legacy_numeric_constants)]
+
+use fast_float::{parse_js, FastFloat};
+
+const NAN: u64 = 0x7FF8_0000_0000_0000;
+
+// generated via Number() in Node.js from the StringNumericLiteral grammar
+#[rustfmt::skip]
+const CASES: &[(&str, u64)] = &[
+    ("", 0x00000000_00000000),
+    (" ", 0x00000000_00000000),
+    ("\t\n\u{b}\u{c}\r", 0x00000000_00000000),
+    ("\u{2028}\u{2029}", 0x00000000_00000000),
+    ("\u{feff}", 0x00000000_00000000),
+    ("\u{85}", NAN),
+    ("\u{200b}", NAN),
+    ("Infinity", 0x7FF00000_00000000),
+    ("+Infinity", 0x7FF00000_00000000),
+    ("-Infinity", 0xFFF00000_00000000),
+    ("infinity", NAN),
+    ("+infinity", NAN),
+    ("-infinity", NAN),
+    ("INFINITY", NAN),
+    ("+INFINITY", NAN),
+    ("-INFINITY", NAN),
+    ("Inf", NAN),
+    ("+Inf", NAN),
+    ("-Inf", NAN),
+    ("inf", NAN),
+    ("+inf", NAN),
+    ("-inf", NAN),
+    ("Infinit", NAN),
+    ("+Infinit", NAN),
+    ("-Infinit", NAN),
+    ("Infinityx", NAN),
+    ("+Infinityx", NAN),
+    ("-Infinityx", NAN),
+    ("NaN", NAN),
+    ("+NaN", NAN),
+    ("-NaN", NAN),
+    ("nan", NAN),
+    ("+nan", NAN),
+    ("-nan", NAN),
+    ("0", 0x00000000_00000000),
+    ("+0", 0x00000000_00000000),
+    ("-0", 0x80000000_00000000),
+    ("1", 0x3FF00000_00000000),
+    ("+1", 0x3FF00000_00000000),
+    ("-1", 0xBFF00000_00000000),
+    ("007", 0x401C0000_00000000),
+    ("+007", 0x401C0000_00000000),
+    ("-007", 0xC01C0000_00000000),
+    ("123", 0x405EC000_00000000),
+    ("+123", 0x405EC000_00000000),
+    ("-123", 0xC05EC000_00000000),
+    ("1.", 0x3FF00000_00000000),
+    ("+1.", 0x3FF00000_00000000),
+    ("-1.", 0xBFF00000_00000000),
+    ("1.5", 0x3FF80000_00000000),
+    ("+1.5", 0x3FF80000_00000000),
+    ("-1.5", 0xBFF80000_00000000),
+    (".5", 0x3FE00000_00000000),
+    ("+.5", 0x3FE00000_00000000),
+    ("-.5", 0xBFE00000_00000000),
+    (".", NAN),
+    ("+.", NAN),
+    ("-.", NAN),
+    ("1.5.5", NAN),
+    ("+1.5.5", NAN),
+    ("-1.5.5", NAN),
+    ("1..5", NAN),
+    ("+1..5", NAN),
+    ("-1..5", NAN),
+    ("..5", NAN),
+    ("+..5", NAN),
+    ("-..5", NAN),
+    ("1e5", 0x40F86A00_00000000),
+    ("+1e5", 0x40F86A00_00000000),
+    ("-1e5", 0xC0F86A00_00000000),
+    ("1E5", 0x40F86A00_00000000),
+    ("+1E5", 0x40F86A00_00000000),
+    ("-1E5", 0xC0F86A00_00000000),
+    ("1e+5", 0x40F86A00_00000000),
+    ("+1e+5", 0x40F86A00_00000000),
+    ("-1e+5", 0xC0F86A00_00000000),
+    ("1e-5", 0x3EE4F8B5_88E368F1),
+    ("+1e-5", 0x3EE4F8B5_88E368F1),
+    ("-1e-5", 0xBEE4F8B5_88E368F1),
+    ("1.e5", 0x40F86A00_00000000),
+    ("+1.e5", 0x40F86A00_00000000),
+    ("-1.e5", 0xC0F86A00_00000000),
+    (".5e5", 0x40E86A00_00000000),
+    ("+.5e5", 0x40E86A00_00000000),
+    ("-.5e5", 0xC0E86A00_00000000),
+    ("1.5E-5", 0x3EEF7510_4D551D69),
+    ("+1.5E-5", 0x3EEF7510_4D551D69),
+    ("-1.5E-5", 0xBEEF7510_4D551D69),
+    ("1e", NAN),
+    ("+1e", NAN),
+    ("-1e", NAN),
+    ("1e+", NAN),
+    ("+1e+", NAN),
+    ("-1e+", NAN),
+    ("e5", NAN),
+    ("+e5", NAN),
+    ("-e5", NAN),
+    (".e5", NAN),
+    ("+.e5", NAN),
+    ("-.e5", NAN),
+    ("1e5.5", NAN),
+    ("+1e5.5", NAN),
+    ("-1e5.5", NAN),
+    ("1e05", 0x40F86A00_00000000),
+    ("+1e05", 0x40F86A00_00000000),
+    ("-1e05", 0xC0F86A00_00000000),
+    ("1_000", NAN),
+    ("+1_000", NAN),
+    ("-1_000", NAN),
+    ("1,5", NAN),
+    ("+1,5", NAN),
+    ("-1,5", NAN),
+    ("1.5f", NAN),
+    ("+1.5f", NAN),
+    ("-1.5f", NAN),
+    ("1d", NAN),
+    ("+1d", NAN),
+    ("-1d", NAN),
+    ("1 5", NAN),
+    ("+1 5", NAN),
+    ("-1 5", NAN),
+    ("5e-324", 0x00000000_00000001),
+    ("+5e-324", 0x00000000_00000001),
+    ("-5e-324", 0x80000000_00000001),
+    ("1e-400", 0x00000000_00000000),
+    ("+1e-400", 0x00000000_00000000),
+    ("-1e-400", 0x80000000_00000000),
+    ("1e400", 0x7FF00000_00000000),
+    ("+1e400", 0x7FF00000_00000000),
+    ("-1e400", 0xFFF00000_00000000),
+    ("1.7976931348623157e308", 0x7FEFFFFF_FFFFFFFF),
+    ("+1.7976931348623157e308", 0x7FEFFFFF_FFFFFFFF),
+    ("-1.7976931348623157e308", 0xFFEFFFFF_FFFFFFFF),
+    ("9007199254740993", 0x43400000_00000000),
+    ("+9007199254740993", 0x43400000_00000000),
+    ("-9007199254740993", 0xC3400000_00000000),
+    ("0.1", 0x3FB99999_9999999A),
+    ("+0.1", 0x3FB99999_9999999A),
+    ("-0.1", 0xBFB99999_9999999A),
+    ("123456789012345678901234567890", 0x45F8EE90_FF6C373E),
+    ("+123456789012345678901234567890", 0x45F8EE90_FF6C373E),
+    ("-123456789012345678901234567890", 0xC5F8EE90_FF6C373E),
+    ("0x", NAN),
+    ("+0x", NAN),
+    ("-0x", NAN),
+    ("0x1F", 0x403F0000_00000000),
+    ("+0x1F", NAN),
+    ("-0x1F", NAN),
+    ("0X1f", 0x403F0000_00000000),
+    ("+0X1f", NAN),
+    ("-0X1f", NAN),
+    ("0xg", NAN),
+    ("+0xg", NAN),
+    ("-0xg", NAN),
+    ("0x1.8", NAN),
+    ("+0x1.8", NAN),
+    ("-0x1.8", NAN),
+    ("0x1p3", NAN),
+    ("+0x1p3", NAN),
+    ("-0x1p3", NAN),
+    ("0x10000000000000000001", 0x44B00000_00000000),
+    ("+0x10000000000000000001", NAN),
+    ("-0x10000000000000000001", NAN),
+    ("0xFFFFFFFFFFFFF800", 0x43EFFFFF_FFFFFFFF),
+    ("+0xFFFFFFFFFFFFF800", NAN),
+    ("-0xFFFFFFFFFFFFF800", NAN),
+    ("0o", NAN),
+    ("+0o", NAN),
+    ("-0o", NAN),
+    ("0o17", 0x402E0000_00000000),
+    ("+0o17", NAN),
+    ("-0o17", NAN),
+    ("0O17", 0x402E0000_00000000),
+    ("+0O17", NAN),
+    ("-0O17", NAN),
+    ("0o8", NAN),
+    ("+0o8", NAN),
+    ("-0o8", NAN),
+    ("0o1.5", NAN),
+    ("+0o1.5", NAN),
+    ("-0o1.5", NAN),
+    ("0b", NAN),
+    ("+0b", NAN),
+    ("-0b", NAN),
+    ("0b101", 0x40140000_00000000),
+    ("+0b101", NAN),
+    ("-0b101", NAN),
+    ("0B11", 0x40080000_00000000),
+    ("+0B11", NAN),
+    ("-0B11", NAN),
+    ("0b2", NAN),
+    ("+0b2", NAN),
+    ("-0b2", NAN),
+    ("00x1", NAN),
+    ("+00x1", NAN),
+    ("-00x1", NAN),
+    ("x1", NAN),
+    ("+x1", NAN),
+    ("-x1", NAN),
+    ("0x_1", NAN),
+    ("+0x_1", NAN),
+    ("-0x_1", NAN),
+    ("0b1_0", NAN),
+    ("+0b1_0", NAN),
+    ("-0b1_0", NAN),
+    ("0x10", 0x40300000_00000000),
+    (" 1.5", 0x3FF80000_00000000),
+    ("1.5 ", 0x3FF80000_00000000),
+    (" -Infinity ", 0xFFF00000_00000000),
+    (" 0x10 ", 0x40300000_00000000),
+    ("\t\n\u{b}\u{c}\r1.5", 0x3FF80000_00000000),
+    ("1.5\t\n\u{b}\u{c}\r", 0x3FF80000_00000000),
+    ("\t\n\u{b}\u{c}\r-Infinity\t\n\u{b}\u{c}\r", 0xFFF00000_00000000),
+    ("\t\n\u{b}\u{c}\r0x10\t\n\u{b}\u{c}\r", 0x40300000_00000000),
+    ("\u{a0}1.5", 0x3FF80000_00000000),
+    ("1.5\u{a0}", 0x3FF80000_00000000),
+    ("\u{a0}-Infinity\u{a0}", 0xFFF00000_00000000),
+    ("\u{a0}0x10\u{a0}", 0x40300000_00000000),
+    ("\u{1680}1.5", 0x3FF80000_00000000),
+    ("1.5\u{1680}", 0x3FF80000_00000000),
+    ("\u{1680}-Infinity\u{1680}", 0xFFF00000_00000000),
+    ("\u{1680}0x10\u{1680}", 0x40300000_00000000),
+    ("\u{2000}\u{200a}1.5", 0x3FF80000_00000000),
+    ("1.5\u{2000}\u{200a}", 0x3FF80000_00000000),
+    ("\u{2000}\u{200a}-Infinity\u{2000}\u{200a}", 0xFFF00000_00000000),
+    ("\u{2000}\u{200a}0x10\u{2000}\u{200a}", 0x40300000_00000000),
+    ("\u{2028}\u{2029}1.5", 0x3FF80000_00000000),
+    ("1.5\u{2028}\u{2029}", 0x3FF80000_00000000),
+    ("\u{2028}\u{2029}-Infinity\u{2028}\u{2029}", 0xFFF00000_00000000),
+    ("\u{2028}\u{2029}0x10\u{2028}\u{2029}", 0x40300000_00000000),
+    ("\u{202f}\u{205f}\u{3000}1.5", 0x3FF80000_00000000),
+    ("1.5\u{202f}\u{205f}\u{3000}", 0x3FF80000_00000000),
+    ("\u{202f}\u{205f}\u{3000}-Infinity\u{202f}\u{205f}\u{3000}", 0xFFF00000_00000000),
+    ("\u{202f}\u{205f}\u{3000}0x10\u{202f}\u{205f}\u{3000}", 0x40300000_00000000),
+    ("\u{feff}1.5", 0x3FF80000_00000000),
+    ("1.5\u{feff}", 0x3FF80000_00000000),
+    ("\u{feff}-Infinity\u{feff}", 0xFFF00000_00000000),
+    ("\u{feff}0x10\u{feff}", 0x40300000_00000000),
+    ("\u{85}1.5", NAN),
+    ("1.5\u{85}", NAN),
+    ("\u{85}-Infinity\u{85}", NAN),
+    ("\u{85}0x10\u{85}", NAN),
+    ("\u{200b}1.5", NAN),
+    ("1.5\u{200b}", NAN),
+    ("\u{200b}-Infinity\u{200b}", NAN),
+    ("\u{200b}0x10\u{200b}", NAN),
+    ("\u{180e}1.5", NAN),
+    ("1.5\u{180e}", NAN),
+    ("\u{180e}-Infinity\u{180e}", NAN),
+    ("\u{180e}0x10\u{180e}", NAN),
+    ("\u{1c}1.5", NAN),
+    ("1.5\u{1c}", NAN),
+    ("\u{1c}-Infinity\u{1c}", NAN),
+    ("\u{1c}0x10\u{1c}", NAN),
+    ("+ 1", NAN),
+    ("- 1", NAN),
+    ("+-1", NAN),
+    ("--1", NAN),
+    ("1.5 x", NAN),
+];
+
+#[test]
+fn test_js_cases() {
+    for &(s, bits) in CASES {
+        let x = parse_js::<f64, _>(s);
+        let y = f32::parse_float_js(s);
+        if bits == NAN {
+            assert!(x.is_nan() && y.is_nan(), "{:?}", s);
+        } else {
+            assert_eq!(x.to_bits(), bits, "{:?}", s);
+            assert_eq!(y, f64::from_bits(bits) as f32, "{:?}", s);
+        }
+    }
+}
+
+#[test]
+fn test_js_whitespace_bytes() {
+    // invalid UTF-8 is never whitespace
+    assert!(parse_js::<f64, _>(&b"1.5\xe2\x80"[..]).is_nan());
+    assert!(parse_js::<f64, _>(&b"\x801.5"[..]).is_nan());
+    assert!(parse_js::<f64, _>(&b"\xff"[..]).is_nan());
+}
+
+#[test]
+fn test_js_radix_long() {
+    let s = format!("0b1{}1", "0".repeat(1000));
+    assert_eq!(parse_js::<f64, _>(&s), 2_f64.powi(1001));
+    let s = format!("0x{}", "f".repeat(300));
+    assert_eq!(parse_js::<f64, _>(&s), core::f64::INFINITY);
+    assert_eq!(
+        parse_js::<f64, _>("0o7777777777777777777777"),
+        2_f64.powi(66)
+    );
+}
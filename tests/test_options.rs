@@ -0,0 +1,499 @@
+use fast_float::{
+    parse_partial_with_options, parse_with_options, DigitSeparator, FastFloat, ParseOptions,
+    SpecialTokens, SpecialValue, Whitespace,
+};
+
+macro_rules! check_ok {
+    ($opts:expr, $s:expr, $x:expr) => {
+        let (opts, s) = (&$opts, $s);
+        check_ok!(opts, s, $x, f32);
+        check_ok!(opts, s, $x, f64);
+    };
+    ($opts:expr, $s:expr, $x:expr, $ty:ty) => {
+        assert_eq!(<$ty>::parse_float_with_options($s, $opts).unwrap(), $x);
+        assert_eq!(parse_with_options::<$ty, _>($s, $opts).unwrap(), $x);
+        assert_eq!(
+            parse_partial_with_options::<$ty, _>($s, $opts).unwrap(),
+            ($x, $s.len())
+        );
+    };
+}
+
+macro_rules! check_ok_partial {
+    ($opts:expr, $s:expr, $x:expr, $n:expr) => {
+        let (opts, s) = (&$opts, $s);
+        check_ok_partial!(opts, s, $x, $n, f32);
+        check_ok_partial!(opts, s, $x, $n, f64);
+    };
+    ($opts:expr, $s:expr, $x:expr, $n:expr, $ty:ty) => {
+        assert!(parse_with_options::<$ty, _>($s, $opts).is_err());
+        assert_eq!(
+            <$ty>::parse_float_partial_with_options($s, $opts).unwrap(),
+            ($x, $n)
+        );
+        assert_eq!(
+            parse_partial_with_options::<$ty, _>($s, $opts).unwrap(),
+            ($x, $n)
+        );
+    };
+}
+
+macro_rules! check_err {
+    ($opts:expr, $s:expr) => {
+        let (opts, s) = (&$opts, $s);
+        check_err!(opts, s, f32);
+        check_err!(opts, s, f64);
+    };
+    ($opts:expr, $s:expr, $ty:ty) => {
+        assert!(parse_with_options::<$ty, _>($s, $opts).is_err());
+        assert!(parse_partial_with_options::<$ty, _>($s, $opts).is_err());
+    };
+}
+
+#[test]
+fn test_default_options() {
+    let opts = ParseOptions::default();
+    assert_eq!(opts, ParseOptions::new());
+    check_ok!(opts, "1.23", 1.23);
+    check_ok!(opts, "+1.", 1.);
+    check_ok!(opts, ".5e1", 5.);
+    check_ok!(opts, "-Infinity", -1. / 0.);
+    check_ok_partial!(opts, "1.5d3", 1.5, 3);
+    check_err!(opts, "");
+    check_err!(opts, ".");
+}
+
+#[test]
+fn test_decimal_point() {
+    let opts = ParseOptions::new().decimal_point(b',');
+    check_ok!(opts, "1,25", 1.25);
+    check_ok!(opts, "-,5e-1", -0.05);
+    check_ok_partial!(opts, "1.25", 1., 1);
+    check_ok_partial!(opts, "1,25,5", 1.25, 4);
+    check_err!(opts, ",");
+    check_err!(opts, ".5");
+}
+
+#[test]
+fn test_decimal_comma() {
+    let opts = ParseOptions::new().decimal_point(b',');
+    check_ok!(opts, "3,25", 3.25);
+    check_ok!(opts, "1,5e-3", 0.0015);
+    check_ok!(opts, "-1,", -1.);
+    check_ok_partial!(opts, "3,5;4,25", 3.5, 3);
+    check_ok_partial!(opts, "1,5e-3;2", 0.0015, 6);
+}
+
+#[test]
+fn test_exponent() {
+    let opts = ParseOptions::new().exponent(b"dD");
+    check_ok!(opts, "1.5d3", 1500.);
+    check_ok!(opts, "1.5D-3", 0.0015);
+    check_ok_partial!(opts, "1.5e3", 1.5, 3);
+
+    let opts = ParseOptions::new().exponent(b"");
+    check_ok_partial!(opts, "1.5e3", 1.5, 3);
+}
+
+#[test]
+fn test_leading_plus() {
+    let opts = ParseOptions::new().allow_leading_plus(false);
+    check_ok!(opts, "-1", -1.);
+    check_ok!(opts, "1e+1", 10.);
+    check_err!(opts, "+1");
+    check_err!(opts, "+inf");
+    check_ok!(opts, "-inf", -1. / 0.);
+}
+
+#[test]
+fn test_leading_trailing_point() {
+    let opts = ParseOptions::new().allow_leading_point(false);
+    check_ok!(opts, "1.", 1.);
+    check_ok!(opts, "0.5", 0.5);
+    check_err!(opts, ".5");
+    check_err!(opts, "-.5");
+
+    let opts = ParseOptions::new().allow_trailing_point(false);
+    check_ok!(opts, ".5", 0.5);
+    check_ok_partial!(opts, "1.", 1., 1);
+    check_ok_partial!(opts, "-1.e5", -1., 2);
+    check_err!(opts, ".");
+    check_err!(opts, ".e1");
+}
+
+#[test]
+fn test_leading_zeros() {
+    let opts = ParseOptions::new().allow_leading_zeros(false);
+    check_ok!(opts, "0", 0.);
+    check_ok!(opts, "-0.25", -0.25);
+    check_ok!(opts, "0e5", 0.);
+    check_ok!(opts, "10.05", 10.05);
+    check_ok!(opts, ".05", 0.05);
+    check_ok_partial!(opts, "01", 0., 1);
+    check_ok_partial!(opts, "-00.5", -0., 2);
+}
+
+#[test]
+fn test_json() {
+    let opts = ParseOptions::json();
+    check_ok!(opts, "0", 0.);
+    check_ok!(opts, "-0", -0.);
+    check_ok!(opts, "123", 123.);
+    check_ok!(opts, "-1.5", -1.5);
+    check_ok!(opts, "0.25e1", 2.5);
+    check_ok!(opts, "1E+2", 100.);
+    check_ok!(opts, "1e-2", 0.01);
+    check_ok!(opts, "10.000000000000000000000000000000000000001", 10.);
+    check_ok_partial!(opts, "01", 0., 1);
+    check_ok_partial!(opts, "-012", -0., 2);
+    check_ok_partial!(opts, "1.", 1., 1);
+    check_ok_partial!(opts, "1.e5", 1., 1);
+    check_ok_partial!(opts, "1e", 1., 1);
+    check_ok_partial!(opts, "1e+", 1., 1);
+    check_ok_partial!(opts, "2.5,", 2.5, 3);
+    check_ok_partial!(opts, "-1]", -1., 2);
+    check_ok_partial!(opts, "1 ", 1., 1);
+    check_err!(opts, "+1");
+    check_err!(opts, ".5");
+    check_err!(opts, "-.5");
+    check_err!(opts, "-");
+    check_err!(opts, " 1");
+    check_err!(opts, "inf");
+    check_err!(opts, "-Infinity");
+    check_err!(opts, "nan");
+    check_err!(opts, "NaN");
+}
+
+#[test]
+fn test_implicit_exponent() {
+    let opts = ParseOptions::new().allow_implicit_exponent(true);
+    check_ok!(opts, "1.5-3", 1.5e-3);
+    check_ok!(opts, "1.5+3", 1.5e3);
+    check_ok!(opts, "-2-2", -0.02);
+    check_ok!(opts, ".5+1", 5.);
+    check_ok!(opts, "1.+1", 10.);
+    check_ok!(opts, "1e-3", 1e-3);
+    check_ok_partial!(opts, "1.5-", 1.5, 3);
+    check_ok_partial!(opts, "1.5+x", 1.5, 3);
+    check_ok_partial!(opts, "1.5e-3-2", 1.5e-3, 6);
+    check_ok_partial!(opts, "1.5--3", 1.5, 3);
+    check_err!(opts, "+-3");
+    check_err!(opts, "-+3");
+    let opts = ParseOptions::new();
+    check_ok_partial!(opts, "1.5-3", 1.5, 3);
+}
+
+#[test]
+fn test_fortran() {
+    let opts = ParseOptions::fortran();
+    check_ok!(opts, "1.0D+05", 1e5);
+    check_ok!(opts, "1.0d-05", 1e-5);
+    check_ok!(opts, "2.5Q-3", 2.5e-3);
+    check_ok!(opts, "2.5q3", 2.5e3);
+    check_ok!(opts, "-1.5E+10", -1.5e10);
+    check_ok!(opts, "1.234-100", 1.234e-100);
+    check_ok!(opts, "0.4940656458412465-323", 5e-324);
+    check_ok!(opts, "1.0000000000000000000000000000001-1", 0.1);
+    check_ok!(opts, "0.12500000000000000000000000000D+01", 1.25);
+    check_ok_partial!(opts, "1.5D", 1.5, 3);
+    check_ok_partial!(opts, "1.5D+", 1.5, 3);
+    check_ok_partial!(opts, "1.5-D3", 1.5, 3);
+    check_ok_partial!(opts, "1.5,-2.5", 1.5, 3);
+    check_err!(opts, "D5");
+}
+
+#[test]
+fn test_whitespace() {
+    let opts = ParseOptions::new();
+    check_ok_partial!(opts, "1.5 ", 1.5, 3);
+    check_err!(opts, " 1.5");
+
+    let opts = ParseOptions::new().whitespace(Whitespace::Ascii);
+    check_ok!(opts, " 1.5", 1.5);
+    check_ok!(opts, "1.5\n", 1.5);
+    check_ok!(opts, " \t\n\x0B\x0C\r-1.5e3 \t\n\x0B\x0C\r", -1.5e3);
+    check_ok!(opts, "  1e0  ", 1.);
+    check_ok!(opts, " 1.00000000000000000000000000000000000000000001 ", 1.);
+    check_ok_partial!(opts, " 1.5 x", 1.5, 5);
+    check_ok_partial!(opts, "1.5 2.5", 1.5, 4);
+    check_err!(opts, "");
+    check_err!(opts, "  ");
+    check_err!(opts, "- 1.5");
+    check_err!(opts, "\u{a0}1.5");
+    check_ok_partial!(opts, "1.5\u{a0}", 1.5, 3);
+
+    let opts = ParseOptions::new().whitespace(Whitespace::Unicode);
+    check_ok!(opts, "\u{a0}1.5\u{202f}", 1.5);
+    check_ok!(opts, "\u{3000}\u{2028} 1.5\r\n\u{85}", 1.5);
+    check_ok_partial!(opts, "1.5\u{a0}\u{200b}", 1.5, 5);
+    let (x, n) = parse_partial_with_options::<f64, _>(b"1.5 \xe2\x80", &opts).unwrap();
+    assert_eq!((x, n), (1.5, 4));
+    check_err!(opts, "\u{feff}1.5");
+    check_err!(opts, "\u{a0}");
+
+    let opts = ParseOptions::json().whitespace(Whitespace::Ascii);
+    check_ok_partial!(opts, " 01 ", 0., 2);
+    check_ok!(opts, "\t-0.5e1\n", -5.);
+}
+
+#[test]
+fn test_special_values() {
+    let opts = ParseOptions::new().allow_special_values(false);
+    check_ok!(opts, "1e3", 1000.);
+    check_err!(opts, "inf");
+    check_err!(opts, "-Infinity");
+    check_err!(opts, "nan");
+}
+
+#[test]
+fn test_special_tokens() {
+    const TOKENS: SpecialTokens = SpecialTokens::new(&[
+        ("Infinity", SpecialValue::Infinity),
+        ("-Infinity", SpecialValue::NegInfinity),
+        ("1.#INF", SpecialValue::Infinity),
+        ("-1.#INF", SpecialValue::NegInfinity),
+        ("1.#QNAN", SpecialValue::NaN),
+        ("-1.#IND", SpecialValue::NegNaN),
+        ("#N/A", SpecialValue::NaN),
+        ("\u{221e}", SpecialValue::Infinity),
+        ("-\u{221e}", SpecialValue::NegInfinity),
+        ("", SpecialValue::NaN),
+    ]);
+    let opts = ParseOptions::new().special_tokens(TOKENS);
+    check_ok!(opts, "Infinity", (1. / 0.));
+    check_ok!(opts, "-Infinity", -(1. / 0.));
+    check_ok!(opts, "1.#INF", (1. / 0.));
+    check_ok!(opts, "-1.#INF", -(1. / 0.));
+    check_ok!(opts, "\u{221e}", (1. / 0.));
+    check_ok!(opts, "-\u{221e}", -(1. / 0.));
+    check_ok!(opts, "1.5", 1.5);
+    check_ok!(opts, "-1", -1.);
+    check_ok_partial!(opts, "1.#INF)", (1. / 0.), 6);
+    check_ok_partial!(opts, "1.#", 1., 2);
+    check_ok_partial!(opts, "1.#inf", 1., 2);
+    check_ok_partial!(opts, "Infinityy", (1. / 0.), 8);
+    check_err!(opts, "inf");
+    check_err!(opts, "nan");
+    check_err!(opts, "+Infinity");
+    check_err!(opts, "INFINITY");
+    check_err!(opts, "");
+    check_err!(opts, "#N/");
+    for &s in &["1.#QNAN", "#N/A", "-1.#IND"] {
+        let x = parse_with_options::<f64, _>(s, &opts).unwrap();
+        assert!(
+            x.is_nan() && x.is_sign_negative() == s.starts_with('-'),
+            "{:?}",
+            s
+        );
+        assert!(parse_with_options::<f32, _>(s, &opts).unwrap().is_nan());
+    }
+
+    let opts = ParseOptions::new().special_tokens(TOKENS.case_sensitive(false));
+    check_ok!(opts, "INFINITY", (1. / 0.));
+    check_ok!(opts, "-infinity", -(1. / 0.));
+    check_ok!(opts, "1.#inf", (1. / 0.));
+    assert!(parse_with_options::<f64, _>("#n/a", &opts)
+        .unwrap()
+        .is_nan());
+
+    let opts = ParseOptions::new()
+        .special_tokens(SpecialTokens::new(&[
+            ("inf", SpecialValue::Infinity),
+            ("infinity", SpecialValue::Infinity),
+            ("in", SpecialValue::NaN),
+        ]))
+        .whitespace(Whitespace::Ascii);
+    check_ok!(opts, "inf", (1. / 0.));
+    check_ok!(opts, " infinity ", (1. / 0.));
+    check_ok_partial!(opts, "infinit", (1. / 0.), 3);
+
+    let opts = ParseOptions::json().special_tokens(TOKENS);
+    check_ok!(opts, "-Infinity", -(1. / 0.));
+    check_ok_partial!(opts, "01", 0., 1);
+    let opts = opts.allow_special_values(false);
+    check_ok_partial!(opts, "1.#INF", 1., 1);
+    check_err!(opts, "Infinity");
+    check_err!(opts, "\u{221e}");
+}
+
+#[test]
+fn test_long_mantissa_options() {
+    let opts = ParseOptions::new().decimal_point(b',').exponent(b"dD");
+    let s = "2,22507385850720113605740979670913197593481954635164564802342610972482222202107694551652952390813508791414915891303962110687008643869459464552765720740782062174337998814106326732925355228688137214901298112245145188984905722230728525513315575501591439747639798341180199932396254828901710708185069063066665599493827577257201576306269066333264756530000000000000000000000000000000000000000000000000000000000000000000d-308";
+    let x: f64 = parse_with_options(s, &opts).unwrap();
+    assert_eq!(x, 2.225073858507201e-308);
+}
+
+#[test]
+fn test_digit_separator() {
+    let opts = ParseOptions::new().digit_separator(DigitSeparator::new(b','));
+    check_ok!(opts, "1,234,567.5", 1_234_567.5);
+    check_ok!(opts, "-1,2.3,4e1", -123.4);
+    check_ok_partial!(opts, "1,234,567.5e1,0", 12_345_675., 13);
+
+    let opts = ParseOptions::new().digit_separator(DigitSeparator::new(b'_'));
+    check_ok!(opts, "1_000_000.5", 1_000_000.5);
+
+    let opts = ParseOptions::new()
+        .decimal_point(b',')
+        .digit_separator(DigitSeparator::new(b' '));
+    check_ok!(opts, "1 234 567,5", 1_234_567.5);
+
+    let opts = ParseOptions::new().digit_separator(DigitSeparator::new(b'\''));
+    check_ok!(opts, "1'234'567", 1_234_567.);
+}
+
+#[test]
+fn test_digit_separator_parts() {
+    let sep = DigitSeparator::new(b'_');
+    let opts = ParseOptions::new().digit_separator(sep.fraction(false));
+    check_ok!(opts, "1_000.25", 1000.25);
+    check_ok_partial!(opts, "1_000.000_1", 1000., 9);
+
+    let opts = ParseOptions::new().digit_separator(sep.integer(false));
+    check_ok!(opts, "1000.000_1", 1000.0001);
+    check_ok_partial!(opts, "1_000.000_1", 1., 1);
+
+    let opts = ParseOptions::new().digit_separator(sep.exponent(true));
+    check_ok!(opts, "1_0e1_0", 1e11);
+    check_ok!(opts, "1.5e-1_0", 1.5e-10);
+    check_ok_partial!(opts, "1e_1", 1., 1);
+    check_ok_partial!(opts, "1e1_", 10., 3);
+
+    let opts = ParseOptions::new().digit_separator(sep.exponent(true).allow_leading(true));
+    check_ok!(opts, "1e_1", 10.);
+    check_ok!(opts, "1e-_1", 0.1);
+    check_ok_partial!(opts, "1e_", 1., 1);
+}
+
+#[test]
+fn test_digit_separator_rules() {
+    let sep = DigitSeparator::new(b'_');
+    let opts = ParseOptions::new().digit_separator(sep);
+    check_err!(opts, "_1");
+    check_err!(opts, "-_1");
+    check_ok_partial!(opts, "1_", 1., 1);
+    check_ok_partial!(opts, "1_.5", 1., 1);
+    check_ok_partial!(opts, "1._5", 1., 2);
+    check_ok_partial!(opts, "1__0", 1., 1);
+    check_ok_partial!(opts, "1_0__0", 10., 3);
+
+    let opts = ParseOptions::new().digit_separator(sep.allow_leading(true));
+    check_ok!(opts, "_1", 1.);
+    check_ok!(opts, "-_1._5", -1.5);
+    check_ok_partial!(opts, "1_", 1., 1);
+
+    let opts = ParseOptions::new().digit_separator(sep.allow_trailing(true));
+    check_ok!(opts, "1_", 1.);
+    check_ok!(opts, "1_.5_", 1.5);
+    check_ok!(opts, "1_.5_e1", 15.);
+    check_ok_partial!(opts, "1__", 1., 1);
+    check_err!(opts, "_1");
+
+    let opts = ParseOptions::new().digit_separator(sep.allow_consecutive(true));
+    check_ok!(opts, "1__0", 10.);
+    check_ok!(opts, "1.2___5", 1.25);
+    check_ok_partial!(opts, "1__", 1., 1);
+}
+
+#[test]
+fn test_digit_separator_long_mantissa() {
+    fn group(s: &str) -> String {
+        let mut out = String::new();
+        let (mantissa, exponent) = s.split_at(s.find('e').unwrap_or(s.len()));
+        let mut n_digits = 0;
+        for c in mantissa.chars() {
+            if c.is_ascii_digit() {
+                if n_digits > 0 && n_digits % 3 == 0 {
+                    out.push('_');
+                }
+                n_digits += 1;
+            } else {
+                n_digits = 0;
+            }
+            out.push(c);
+        }
+        out + exponent
+    }
+
+    let opts = ParseOptions::new().digit_separator(DigitSeparator::new(b'_'));
+    let mut inputs = vec![
+        "9007199254740993".to_owned(),
+        "12345678901234567.1234567890123456".to_owned(),
+        "9007199254740993.0000000000000000000000000000000000000001".to_owned(),
+        "1.00000000000000011102230246251565404236316680908203125".to_owned(),
+        "1.00000000000000011102230246251565404236316680908203124".to_owned(),
+        "0.000000000000000000000000000000000000000000000000000000000000001e60".to_owned(),
+        "179769313486231580793728971405301e276".to_owned(),
+    ];
+    inputs.push(format!("{}{}", "9007199254740993.", "0".repeat(1000)));
+    inputs.push(format!(
+        "{}{}",
+        "0.",
+        "0".repeat(400) + "49406564584124654e83"
+    ));
+    for s in &inputs {
+        let grouped = group(s);
+        let expected: f64 = fast_float::parse(s).unwrap();
+        let actual: f64 = parse_with_options(&grouped, &opts).unwrap();
+        assert_eq!(actual, expected, "{}", grouped);
+        let expected: f32 = fast_float::parse(s).unwrap();
+        let actual: f32 = parse_with_options(&grouped, &opts).unwrap();
+        assert_eq!(actual, expected, "{}", grouped);
+    }
+}
+
+#[test]
+fn test_nan_payload() {
+    let check = |s: &str, bits64: u64, bits32: u32, n: usize| {
+        let opts = ParseOptions::new().allow_nan_payload(true);
+        let (x, n64) = parse_partial_with_options::<f64, _>(s, &opts).unwrap();
+        let (y, n32) = parse_partial_with_options::<f32, _>(s, &opts).unwrap();
+        assert_eq!((x.to_bits(), n64), (bits64, n), "{:?}", s);
+        assert_eq!((y.to_bits(), n32), (bits32, n), "{:?}", s);
+    };
+    check("nan", 0x7ff8_0000_0000_0000, 0x7fc0_0000, 3);
+    check("-NaN", 0xfff8_0000_0000_0000, 0xffc0_0000, 4);
+    check("nan(123)", 0x7ff8_0000_0000_007b, 0x7fc0_007b, 8);
+    check("+nan(0x1f)", 0x7ff8_0000_0000_001f, 0x7fc0_001f, 10);
+    check("nan(0X1F)", 0x7ff8_0000_0000_001f, 0x7fc0_001f, 9);
+    check("nan(017)", 0x7ff8_0000_0000_000f, 0x7fc0_000f, 8);
+    check("nan(0)", 0x7ff8_0000_0000_0000, 0x7fc0_0000, 6);
+    check("-nan(0x12345678)", 0xfff8_0000_1234_5678, 0xfff4_5678, 16);
+    check(
+        "nan(0xfffffffffffffffff)",
+        0x7fff_ffff_ffff_ffff,
+        0x7fff_ffff,
+        24,
+    );
+    check("snan", 0x7ff4_0000_0000_0000, 0x7fa0_0000, 4);
+    check("-sNaN", 0xfff4_0000_0000_0000, 0xffa0_0000, 5);
+    check("snan(1)", 0x7ff0_0000_0000_0001, 0x7f80_0001, 7);
+    check(
+        "snan(0x4000000000000)",
+        0x7ff4_0000_0000_0000,
+        0x7fa0_0000,
+        21,
+    );
+    check("nan()", 0x7ff8_0000_0000_0000, 0x7fc0_0000, 3);
+    check("nan(abc)", 0x7ff8_0000_0000_0000, 0x7fc0_0000, 3);
+    check("nan(0x)", 0x7ff8_0000_0000_0000, 0x7fc0_0000, 3);
+    check("nan(08)", 0x7ff8_0000_0000_0000, 0x7fc0_0000, 3);
+    check("nan(-1)", 0x7ff8_0000_0000_0000, 0x7fc0_0000, 3);
+    check("nan(12", 0x7ff8_0000_0000_0000, 0x7fc0_0000, 3);
+    check("nan (1)", 0x7ff8_0000_0000_0000, 0x7fc0_0000, 3);
+    check("snan(", 0x7ff4_0000_0000_0000, 0x7fa0_0000, 4);
+
+    let opts = ParseOptions::new().allow_nan_payload(true);
+    check_ok!(opts, "inf", (1. / 0.));
+    check_ok!(opts, "-infinity", -(1. / 0.));
+    check_ok!(opts, "1.5", 1.5);
+    check_err!(opts, "sna");
+    check_err!(opts, "s");
+
+    // without the option, neither the payload nor "snan" is special
+    let opts = ParseOptions::new();
+    check_err!(opts, "snan");
+    let (x, n) = parse_partial_with_options::<f64, _>("nan(1)", &opts).unwrap();
+    assert_eq!((x.to_bits(), n), (0x7ff8_0000_0000_0000, 3));
+}
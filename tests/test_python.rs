@@ -0,0 +1,143 @@
+#![allow(clippy::legacy_numeric_constants)]
+
+use fast_float::{parse_python, FastFloat};
+
+macro_rules! check_ok {
+    ($s:expr, $x:expr) => {
+        let s = $s;
+        assert_eq!(
+            parse_python::<f64, _>(s).unwrap().to_bits(),
+            ($x as f64).to_bits(),
+            "{:?}",
+            s
+        );
+        assert_eq!(
+            f32::parse_float_python(s.as_bytes()).unwrap(),
+            $x as f32,
+            "{:?}",
+            s
+        );
+    };
+}
+
+macro_rules! check_nan {
+    ($s:expr, $negative:expr) => {
+        let s = $s;
+        let x = parse_python::<f64, _>(s).unwrap();
+        assert!(x.is_nan() && x.is_sign_negative() == $negative, "{:?}", s);
+        assert!(parse_python::<f32, _>(s).unwrap().is_nan(), "{:?}", s);
+    };
+}
+
+macro_rules! check_err {
+    ($s:expr) => {
+        let s = $s;
+        assert!(parse_python::<f64, _>(s).is_err(), "{:?}", s);
+        assert!(parse_python::<f32, _>(s).is_err(), "{:?}", s);
+    };
+}
+
+#[test]
+fn test_python_docs() {
+    // examples from the documentation of float()
+    check_ok!("+1.23", 1.23);
+    check_ok!("   -12345\n", -12345.);
+    check_ok!("1e-003", 0.001);
+    check_ok!("+1E6", 1_000_000.);
+    check_ok!("-Infinity", core::f64::NEG_INFINITY);
+}
+
+#[test]
+fn test_python_special() {
+    check_ok!("inf", core::f64::INFINITY);
+    check_ok!("iNfInItY", core::f64::INFINITY);
+    check_ok!("-iNF", core::f64::NEG_INFINITY);
+    check_ok!("  \t+infinity\n ", core::f64::INFINITY);
+    check_nan!("nan", false);
+    check_nan!("-nan", true);
+    check_nan!("+NaN", false);
+    check_err!("infinit");
+    check_err!("infinityy");
+    check_err!("inf1");
+    check_err!("nan1");
+    check_err!("nanx");
+    check_err!("in");
+    check_err!("i nf");
+    check_err!("_inf");
+    check_err!("inf_");
+    check_err!("in_f");
+}
+
+#[test]
+fn test_python_underscores() {
+    // valid and invalid float literals from CPython's test_float.py and PEP 515
+    check_ok!("1_00_00.5", 10_000.5);
+    check_ok!("1_00_00.5e5", 1_000_050_000.);
+    check_ok!("1_00_00e1_5", 1e19);
+    check_ok!("1e1_0", 1e10);
+    check_ok!(".1_4", 0.14);
+    check_ok!(".1_4e1", 1.4);
+    check_ok!("0_0_0", 0.);
+    check_ok!("4_2", 42.);
+    check_ok!("1_0000_0000", 100_000_000.);
+    check_ok!("1_000.000_1", 1000.0001);
+    check_err!("0_");
+    check_err!("42_");
+    check_err!("1_.4");
+    check_err!("1._4");
+    check_err!("1.4_");
+    check_err!("._5");
+    check_err!("1.0e+_1");
+    check_err!("1.0e_1");
+    check_err!("1.4e5_");
+    check_err!("1__0");
+    check_err!("1_0__0");
+    check_err!("1e1__0");
+    check_err!("_1");
+    check_err!("1_000_");
+}
+
+#[test]
+fn test_python_whitespace() {
+    check_ok!("  2.75  ", 2.75);
+    check_ok!("\x0b\x0c1.5\r\n", 1.5);
+    check_ok!("\u{85}1.5", 1.5);
+    check_ok!("\u{a0}1.5\u{2003}", 1.5);
+    check_ok!("\u{1680}1.5\u{3000}", 1.5);
+    check_ok!("\u{2028}1.5\u{2029}", 1.5);
+    check_ok!("\u{202f}1.5\u{205f}", 1.5);
+    check_err!("");
+    check_err!("   ");
+    check_err!("1 .5");
+    check_err!("\x1c1.5");
+    check_err!("1.5\x1f");
+    check_err!("\u{feff}1.5");
+    check_err!("\u{200b}1.5");
+    check_err!("1.5\x00");
+    check_err!(&b"1.5 \xe2\x80"[..]);
+}
+
+#[test]
+fn test_python_misc() {
+    check_ok!("1.", 1.);
+    check_ok!(".5", 0.5);
+    check_ok!("00012", 12.);
+    check_ok!("1e+5", 1e5);
+    check_ok!("1E-5", 1e-5);
+    check_ok!("1e500", core::f64::INFINITY);
+    check_ok!("-1e-500", -0.);
+    check_ok!("9007199254740993", 9_007_199_254_740_992_u64 as f64);
+    check_err!("0x1p0");
+    check_err!("0x10");
+    check_err!("+-1");
+    check_err!("--1");
+    check_err!("1e");
+    check_err!("1e+");
+    check_err!(".");
+    check_err!("-.");
+    check_err!("+");
+    check_err!("e5");
+    check_err!("1,5");
+    check_err!("1.5f");
+    check_err!("1.2.3");
+}
@@ -0,0 +1,245 @@
+use fast_float::{parse_radix, parse_radix_partial, FastFloat};
+use num_bigint::BigUint;
+
+/// Correctly rounded bits of a float in the given radix, computed via big integers.
+fn reference_bits(s: &str, radix: u32, mantissa_bits: u32, bias: i64) -> u64 {
+    let s = s.trim_start_matches(&['+', '-'][..]);
+    let (integer, fraction) = match s.find('.') {
+        Some(i) => (&s[..i], &s[i + 1..]),
+        None => (s, ""),
+    };
+    let digits: Vec<u8> = integer
+        .chars()
+        .chain(fraction.chars())
+        .map(|c| c.to_digit(radix).unwrap() as u8)
+        .collect();
+    let num = BigUint::from_radix_be(&digits, radix).unwrap();
+    let den = BigUint::from(radix).pow(fraction.len() as u32);
+    if num == BigUint::from(0_u32) {
+        return 0;
+    }
+
+    // find q = num / den * 2^-e with mantissa_bits + 1 bits, or less for subnormals
+    let min_e = 1 - bias - mantissa_bits as i64;
+    let mut e = (num.bits() as i64 - den.bits() as i64) - mantissa_bits as i64 - 1;
+    e = e.max(min_e);
+    let quotient = |e: i64| {
+        let (n, d) = if e < 0 {
+            (&num << (-e) as usize, den.clone())
+        } else {
+            (num.clone(), &den << e as usize)
+        };
+        (&n / &d, &n % &d, d)
+    };
+    let (mut q, mut r, mut d) = quotient(e);
+    while q.bits() > mantissa_bits as u64 + 1 {
+        e += 1;
+        let next = quotient(e);
+        q = next.0;
+        r = next.1;
+        d = next.2;
+    }
+    let mut q: u64 = q.to_u64_digits().first().copied().unwrap_or(0);
+    let twice = &r << 1_usize;
+    if twice > d || (twice == d && q & 1 == 1) {
+        q += 1;
+    }
+    if q >> (mantissa_bits + 1) != 0 {
+        q >>= 1;
+        e += 1;
+    }
+    if q >> mantissa_bits == 0 {
+        return q; // subnormal
+    }
+    let biased = e - min_e + 1;
+    if biased > 2 * bias {
+        return ((2 * bias + 1) as u64) << mantissa_bits;
+    }
+    ((biased as u64) << mantissa_bits) | (q & ((1 << mantissa_bits) - 1))
+}
+
+fn check(s: &str, radix: u32) {
+    let negative = s.starts_with('-');
+    let x = parse_radix::<f64, _>(s, radix).unwrap();
+    let expected = reference_bits(s, radix, 52, 1023) | ((negative as u64) << 63);
+    assert_eq!(x.to_bits(), expected, "{} (radix {})", s, radix);
+    let x = parse_radix::<f32, _>(s, radix).unwrap();
+    let expected = reference_bits(s, radix, 23, 127) as u32 | ((negative as u32) << 31);
+    assert_eq!(x.to_bits(), expected, "{} (radix {})", s, radix);
+    assert_eq!(f64::parse_float_radix_partial(s, radix).unwrap().1, s.len());
+}
+
+macro_rules! check_ok {
+    ($s:expr, $radix:expr, $x:expr) => {
+        let (s, radix) = ($s, $radix);
+        assert_eq!(parse_radix::<f64, _>(s, radix).unwrap(), $x);
+        assert_eq!(f32::parse_float_radix(s.as_bytes(), radix).unwrap(), $x);
+        check(s, radix);
+    };
+}
+
+macro_rules! check_ok_partial {
+    ($s:expr, $radix:expr, $x:expr, $n:expr) => {
+        let (s, radix) = ($s, $radix);
+        assert!(parse_radix::<f64, _>(s, radix).is_err());
+        assert_eq!(parse_radix_partial::<f64, _>(s, radix).unwrap(), ($x, $n));
+        assert_eq!(parse_radix_partial::<f32, _>(s, radix).unwrap(), ($x, $n));
+    };
+}
+
+macro_rules! check_err {
+    ($s:expr, $radix:expr) => {
+        let (s, radix) = ($s, $radix);
+        assert!(parse_radix::<f64, _>(s, radix).is_err());
+        assert!(parse_radix_partial::<f64, _>(s, radix).is_err());
+        assert!(parse_radix_partial::<f32, _>(s, radix).is_err());
+    };
+}
+
+#[test]
+fn test_radix_basic() {
+    check_ok!("101.011", 2, 5.375);
+    check_ok!("-101.011", 2, -5.375);
+    check("z.z", 36);
+    check_ok!("Z.I", 36, 35.5);
+    check_ok!("0.1", 3, 1. / 3.);
+    check_ok!("+17.4", 8, 15.5);
+    check_ok!("ff.8", 16, 255.5);
+    check_ok!("e", 15, 14.);
+    check_ok!("10", 36, 36.);
+    check_ok!("1.5", 10, 1.5);
+    check_ok!(".2", 4, 0.5);
+    check_ok!("2.", 7, 2.);
+    check_ok!("-0", 5, -0.);
+    check_ok!("000.000", 12, 0.);
+}
+
+#[test]
+fn test_radix_partial() {
+    check_ok_partial!("1.2", 2, 1., 2);
+    check_ok_partial!("12", 2, 1., 1);
+    check_ok_partial!("1e5", 10, 1., 1);
+    check_ok_partial!("1.2.1", 4, 1.5, 3);
+    check_ok_partial!("az ", 36, 395., 2);
+    check_err!("", 2);
+    check_err!("-", 2);
+    check_err!(".", 3);
+    check_err!("2", 2);
+    check_err!("inf", 10);
+    check_err!("nan", 16);
+    check_err!(" 1", 8);
+    check_err!("g", 16);
+    check_ok!("inf", 36, 24_171.);
+}
+
+#[test]
+#[should_panic]
+fn test_radix_too_small() {
+    let _ = parse_radix::<f64, _>("0", 1);
+}
+
+#[test]
+#[should_panic]
+fn test_radix_too_large() {
+    let _ = parse_radix::<f64, _>("0", 37);
+}
+
+#[test]
+fn test_radix_extremes() {
+    for radix in 2..=36 {
+        let digit = std::char::from_digit(radix - 1, radix).unwrap();
+        let long = digit.to_string().repeat(1200);
+        check(&long, radix);
+        check(&format!("0.{}", long), radix);
+        check(&format!("0.{}1", "0".repeat(1200)), radix);
+        check(&format!("1{}", "0".repeat(1200)), radix);
+        // digits just around the smallest subnormal
+        let n_zeros = 1074. * 2_f64.ln() / (radix as f64).ln();
+        let zeros = "0".repeat(n_zeros as usize - 1);
+        for tail in &[
+            "1",
+            "2",
+            "9",
+            "z",
+            "1z",
+            "0000001",
+            "11111111111111111111111111111111",
+        ] {
+            if tail.chars().all(|c| c.is_digit(radix)) {
+                check(&format!("0.{}{}", zeros, tail), radix);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_radix_halfway() {
+    // digits of halfway points between floats, along with their neighbours
+    fn expand(num: &BigUint, den: &BigUint, radix: u32, n_digits: usize) -> String {
+        let mut s = (num / den).to_str_radix(radix) + ".";
+        let mut rem = num % den;
+        for _ in 0..n_digits {
+            rem *= radix;
+            let digit = (&rem / den).to_u64_digits().first().copied().unwrap_or(0);
+            s.push(std::char::from_digit(digit as u32, radix).unwrap());
+            rem %= den;
+        }
+        s
+    }
+    let rng = fastrand::Rng::with_seed(0);
+    for radix in 2..=36 {
+        for _ in 0..20 {
+            let mantissa = 2 * ((1_u64 << 52) | rng.u64(..1 << 52)) + 1;
+            let exponent = rng.i32(-1100..1000);
+            let (num, den) = if exponent < 0 {
+                (
+                    BigUint::from(mantissa),
+                    BigUint::from(1_u32) << (-exponent) as usize,
+                )
+            } else {
+                (
+                    BigUint::from(mantissa) << exponent as usize,
+                    BigUint::from(1_u32),
+                )
+            };
+            let n_digits = rng.usize(1..1500);
+            let s = expand(&num, &den, radix, n_digits);
+            check(&s, radix);
+            check(&format!("{}1", s), radix);
+            let last = s.chars().last().unwrap().to_digit(radix).unwrap();
+            if last != 0 {
+                let below = &s[..s.len() - 1];
+                check(
+                    &format!(
+                        "{}{}",
+                        below,
+                        std::char::from_digit(last - 1, radix).unwrap()
+                    ),
+                    radix,
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_radix_random() {
+    let rng = fastrand::Rng::with_seed(0);
+    for radix in 2..=36 {
+        for _ in 0..1000 {
+            let n_digits = if rng.bool() {
+                rng.usize(1..30)
+            } else {
+                rng.usize(1..300)
+            };
+            let mut s: String = (0..n_digits)
+                .map(|_| std::char::from_digit(rng.u32(..radix), radix).unwrap())
+                .collect();
+            s.insert(rng.usize(..=n_digits), '.');
+            if rng.bool() {
+                s.insert(0, '-');
+            }
+            check(&s, radix);
+        }
+    }
+}
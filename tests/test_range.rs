@@ -0,0 +1,134 @@
+#![allow(clippy::excessive_precision)]
+
+use fast_float::{
+    parse_detailed_with_options, parse_partial_with_range, parse_with_options, parse_with_range,
+    FastFloat, ParseError, ParseOptions, RangeStatus, Whitespace,
+};
+
+use RangeStatus::{InRange, Overflow, Subnormal, Underflow};
+
+macro_rules! check_range {
+    ($ty:ty, $s:expr, $x:expr, $range:expr) => {
+        let (s, x, range) = ($s, $x, $range);
+        let options = ParseOptions::new();
+        let (value, r) = parse_with_range::<$ty, _>(s, &options).unwrap();
+        assert_eq!(
+            (value.to_bits(), r),
+            ((x as $ty).to_bits(), range),
+            "{:?}",
+            s
+        );
+        let strict = options.allow_out_of_range(false);
+        if range == InRange {
+            assert_eq!(parse_with_options::<$ty, _>(s, &strict).unwrap(), value);
+        } else {
+            assert!(parse_with_options::<$ty, _>(s, &strict).is_err(), "{:?}", s);
+            let e = parse_detailed_with_options::<$ty, _>(s, &strict).unwrap_err();
+            assert_eq!(e, ParseError::OutOfRange { position: 0, range });
+        }
+    };
+}
+
+#[test]
+fn test_range_f64() {
+    check_range!(f64, "1.5", 1.5, InRange);
+    check_range!(f64, "0", 0., InRange);
+    check_range!(f64, "-0e-999", -0., InRange);
+    check_range!(f64, "0.00000000000000000000000000000e999", 0., InRange);
+    check_range!(
+        f64,
+        "1.7976931348623157e308",
+        1.7976931348623157e308,
+        InRange
+    );
+    check_range!(
+        f64,
+        "1.7976931348623158e308",
+        1.7976931348623157e308,
+        InRange
+    );
+    check_range!(f64, "1.7976931348623159e308", 1. / 0., Overflow);
+    check_range!(f64, "-1e400", -1. / 0., Overflow);
+    check_range!(f64, "1e99999999999", 1. / 0., Overflow);
+    check_range!(
+        f64,
+        "2.2250738585072014e-308",
+        2.2250738585072014e-308,
+        InRange
+    );
+    check_range!(
+        f64,
+        "2.2250738585072011e-308",
+        2.2250738585072011e-308,
+        Subnormal
+    );
+    check_range!(f64, "4.9e-324", 5e-324, Subnormal);
+    check_range!(f64, "-2.4703282292062328e-324", -5e-324, Subnormal);
+    check_range!(f64, "2.4703282292062327e-324", 0., Underflow);
+    check_range!(f64, "-1e-400", -0., Underflow);
+    check_range!(f64, "1e-99999999999", 0., Underflow);
+    check_range!(f64, "inf", 1. / 0., InRange);
+    check_range!(f64, "-infinity", -1. / 0., InRange);
+}
+
+#[test]
+fn test_range_f32() {
+    check_range!(f32, "3.4028235e38", 3.4028235e38_f32, InRange);
+    check_range!(f32, "3.5e38", 1. / 0., Overflow);
+    check_range!(f32, "1e300", 1. / 0., Overflow);
+    check_range!(f32, "1.17549435e-38", 1.17549435e-38_f32, InRange);
+    check_range!(f32, "1e-38", 1e-38_f32, Subnormal);
+    check_range!(f32, "1e-45", 1e-45_f32, Subnormal);
+    check_range!(f32, "1e-46", 0., Underflow);
+}
+
+#[test]
+fn test_range_long() {
+    // just below and just above the halfway point between zero and the smallest subnormal
+    let below = "2.47032822920623272088284396434110686182e-324";
+    check_range!(f64, below, 0., Underflow);
+    let above = "2.47032822920623272088284396434110686183e-324";
+    check_range!(f64, above, 5e-324, Subnormal);
+    let big = format!("1{}", "0".repeat(309));
+    check_range!(f64, big.as_str(), 1. / 0., Overflow);
+    let max = format!("17976931348623157{}", "0".repeat(292));
+    check_range!(f64, max.as_str(), 1.7976931348623157e308, InRange);
+}
+
+#[test]
+fn test_range_partial() {
+    let options = ParseOptions::new();
+    let r = parse_partial_with_range::<f64, _>("1e400x", &options).unwrap();
+    assert_eq!(r, (1. / 0., 5, Overflow));
+    let r = f32::parse_float_partial_with_range("1e-50, 2", &options).unwrap();
+    assert_eq!(r, (0., 5, Underflow));
+    assert!(parse_with_range::<f64, _>("1e400x", &options).is_err());
+
+    let strict = ParseOptions::new()
+        .allow_out_of_range(false)
+        .whitespace(Whitespace::Ascii);
+    assert!(parse_partial_with_range::<f64, _>("1e400x", &strict).is_err());
+    let r = parse_partial_with_range::<f64, _>(" 1e300 x", &strict).unwrap();
+    assert_eq!(r, (1e300, 7, InRange));
+    let e = parse_detailed_with_options::<f32, _>("  -1e39 ", &strict).unwrap_err();
+    assert_eq!(
+        e,
+        ParseError::OutOfRange {
+            position: 2,
+            range: Overflow
+        }
+    );
+    assert_eq!(e.to_string(), "number at position 2 overflows to infinity");
+    let e = parse_detailed_with_options::<f64, _>("1e-400x", &strict).unwrap_err();
+    assert_eq!(e.to_string(), "number at position 0 underflows to zero");
+    let e = parse_detailed_with_options::<f64, _>("1e-320", &strict).unwrap_err();
+    assert_eq!(e.to_string(), "number at position 0 is subnormal");
+    let e = parse_detailed_with_options::<f64, _>("1e300x", &strict).unwrap_err();
+    assert_eq!(
+        e,
+        ParseError::TrailingCharacters {
+            consumed: 5,
+            found: 'x'
+        }
+    );
+}
@@ -0,0 +1,165 @@
+use fast_float::{parse_rust_literal, parse_rust_literal_partial, FastFloat, RustSuffix};
+
+macro_rules! check_ok {
+    ($s:expr, $x:expr) => {
+        check_ok!($s, $x, None);
+    };
+    ($s:expr, $x:expr, $suffix:expr) => {
+        let s = $s;
+        assert_eq!(parse_rust_literal::<f64, _>(s).unwrap(), ($x, $suffix));
+        assert_eq!(
+            f32::parse_rust_literal(s.as_bytes()).unwrap(),
+            ($x, $suffix)
+        );
+        assert_eq!(
+            parse_rust_literal_partial::<f64, _>(s).unwrap(),
+            ($x, s.len(), $suffix)
+        );
+    };
+}
+
+macro_rules! check_ok_partial {
+    ($s:expr, $x:expr, $n:expr) => {
+        check_ok_partial!($s, $x, $n, None);
+    };
+    ($s:expr, $x:expr, $n:expr, $suffix:expr) => {
+        let s = $s;
+        assert!(parse_rust_literal::<f64, _>(s).is_err());
+        assert_eq!(
+            parse_rust_literal_partial::<f64, _>(s).unwrap(),
+            ($x, $n, $suffix)
+        );
+        assert_eq!(
+            parse_rust_literal_partial::<f32, _>(s).unwrap(),
+            ($x, $n, $suffix)
+        );
+    };
+}
+
+macro_rules! check_err {
+    ($s:expr) => {
+        let s = $s;
+        assert!(parse_rust_literal::<f64, _>(s).is_err());
+        assert!(parse_rust_literal_partial::<f64, _>(s).is_err());
+        assert!(f32::parse_rust_literal_partial(s).is_err());
+    };
+}
+
+#[test]
+fn test_rust_literal_basic() {
+    check_ok!("1.5", 1.5);
+    check_ok!("0.25", 0.25);
+    check_ok!("1.", 1.);
+    check_ok!("1e3", 1e3);
+    check_ok!("1E-3", 1e-3);
+    check_ok!("2.5e+2", 2.5e+2);
+    check_ok!("1_000.5", 1_000.5);
+    check_ok!("1_000.5e1_0", 1_000.5e1_0);
+    check_ok!("1__0_.0_", 10.);
+    check_ok!("1_.", 1.);
+    check_ok!("1e_5", 1e5);
+    check_ok!("1e+_5_", 1e5);
+    check_ok!("00.5", 0.5);
+}
+
+#[test]
+fn test_rust_literal_suffix() {
+    check_ok!("1.5f32", 1.5, Some(RustSuffix::F32));
+    check_ok!("1.5f64", 1.5, Some(RustSuffix::F64));
+    check_ok!("1f32", 1., Some(RustSuffix::F32));
+    check_ok!("1_f64", 1., Some(RustSuffix::F64));
+    check_ok!("1e3f32", 1e3, Some(RustSuffix::F32));
+    check_ok!("1.5_f32", 1.5, Some(RustSuffix::F32));
+    check_ok_partial!("1f32)", 1., 4, Some(RustSuffix::F32));
+    check_err!("1f");
+    check_err!("1f16");
+    check_err!("1f320");
+    check_err!("1.5F32");
+    check_err!("1.5f32_");
+    check_err!("1.5u8");
+    check_err!("1.5x");
+}
+
+#[test]
+fn test_rust_literal_partial() {
+    check_ok_partial!("1.5;", 1.5, 3);
+    check_ok_partial!("1.5.0", 1.5, 3);
+    check_ok_partial!("1. + 2.", 1., 2);
+    check_ok_partial!("1.)", 1., 2);
+    check_ok_partial!("1e5-2", 1e5, 3);
+    check_ok_partial!("2.0f64 * x", 2., 6, Some(RustSuffix::F64));
+}
+
+#[test]
+fn test_rust_literal_errors() {
+    check_err!("");
+    check_err!("1");
+    check_err!("1_000");
+    check_err!("-1.5");
+    check_err!("+1.5");
+    check_err!(".5");
+    check_err!("_1.5");
+    check_err!("1..2");
+    check_err!("1.max(2)");
+    check_err!("1._5");
+    check_err!("1.e5");
+    check_err!("1.f32");
+    check_err!("1e");
+    check_err!("1e+");
+    check_err!("1e_");
+    check_err!("1.5e");
+    check_err!("inf");
+    check_err!("NaN");
+    check_err!("0x1p5");
+    check_err!("1.5é");
+}
+
+#[test]
+fn test_rust_literal_long() {
+    let s = format!("0.{}1_e3_0_0", "0_0".repeat(200));
+    let x: f64 = format!("0.{}1e300", "00".repeat(200)).parse().unwrap();
+    assert_eq!(parse_rust_literal::<f64, _>(&s).unwrap(), (x, None));
+    let s = "2.225_073_858_507_201_136_057_409_796_709_131_975_934_819_546_351_645_648_023_426_109_724_822_222_021_076_945_516_529_523_908_135_087_914_149_158_913_039_621_106_870_086_438_694_594_645_527_657_207_407_820_621_743_379_988_141_063_267_329_253_552_286_881_372_149_012_981_122_451_451_889_849_057_222_307_285_255_133_155_755_015_914_397_476_397_983_411_801_999_323_962_548_289_017_107_081_850_690_630_666_655_994_938_275_772_572_015_763_062_690_663_332_647_565_300_009_245_888_316_433_037_779_791_869_612_049_497_390_377_829_704_905_051_080_609_940_730_262_937_128_958_950_003_583_799_967_207_254_304_360_284_078_895_771_796_150_945_516_748_243_471_030_702_609_144_621_572_289_880_258_182_545_180_325_707_018_860_872_113_128_079_512_233_426_288_368_622_321_503_775_666_622_503_982_534_335_974_568_884_423_900_265_498_198_385_487_948_292_206_894_721_689_831_099_698_365_846_814_022_854_243_330_660_339_850_886_445_804_001_034_933_970_427_567_186_443_383_770_486_037_861_622_771_738_545_623_065_874_679_014_086_723_327_636_718_751_234_567_890_123_456_789_012_345_678_901e-3_08";
+    let x: f64 = s.replace('_', "").parse().unwrap();
+    assert_eq!(parse_rust_literal::<f64, _>(s).unwrap(), (x, None));
+}
+
+#[test]
+fn test_rust_literal_random() {
+    let rng = fastrand::Rng::with_seed(0);
+    for _ in 0..10_000 {
+        let digits = |n: usize| -> String {
+            let mut s = rng.char('0'..='9').to_string();
+            for _ in 0..n {
+                s.push(if rng.u8(..4) == 0 {
+                    '_'
+                } else {
+                    rng.char('0'..='9')
+                });
+            }
+            s
+        };
+        let mut s = digits(rng.usize(..10));
+        if rng.bool() {
+            s += ".";
+            s += &digits(rng.usize(..30));
+        }
+        if rng.bool() {
+            s += if rng.bool() { "e-" } else { "E" };
+            s += &digits(rng.usize(..2));
+        }
+        let expected: f64 = s.replace('_', "").parse().unwrap();
+        if s.contains(&['.', 'e', 'E'][..]) {
+            assert_eq!(
+                parse_rust_literal::<f64, _>(&s).unwrap(),
+                (expected, None),
+                "{}",
+                s
+            );
+        }
+        s += "f32";
+        let expected: f32 = s[..s.len() - 3].replace('_', "").parse().unwrap();
+        let actual = parse_rust_literal::<f32, _>(&s).unwrap();
+        assert_eq!(actual, (expected, Some(RustSuffix::F32)), "{}", s);
+    }
+}
@@ -0,0 +1,111 @@
+use fast_float::{parse_strtod, FastFloat};
+
+// (input, strtod() bits, strtof() bits, number of characters consumed) as reported by glibc
+#[rustfmt::skip]
+const CASES: &[(&str, u64, u32, usize)] = &[
+    ("1.5", 0x3ff8000000000000, 0x3fc00000, 3),
+    ("  \t\n1.5", 0x3ff8000000000000, 0x3fc00000, 7),
+    ("\x0b\x0c\r 42", 0x4045000000000000, 0x42280000, 6),
+    ("+.5e1", 0x4014000000000000, 0x40a00000, 5),
+    ("-1.", 0xbff0000000000000, 0xbf800000, 3),
+    ("1e", 0x3ff0000000000000, 0x3f800000, 1),
+    ("1e+", 0x3ff0000000000000, 0x3f800000, 1),
+    ("1.5e+3x", 0x4097700000000000, 0x44bb8000, 6),
+    ("1.2.3", 0x3ff3333333333333, 0x3f99999a, 3),
+    ("1,5", 0x3ff0000000000000, 0x3f800000, 1),
+    ("0x1.8p3", 0x4028000000000000, 0x41400000, 7),
+    ("-0x1.8p1", 0xc008000000000000, 0xc0400000, 8),
+    ("0X1A", 0x403a000000000000, 0x41d00000, 4),
+    ("0x.8", 0x3fe0000000000000, 0x3f000000, 4),
+    ("0x1.", 0x3ff0000000000000, 0x3f800000, 4),
+    ("0x", 0x0000000000000000, 0x00000000, 1),
+    ("-0x", 0x8000000000000000, 0x80000000, 2),
+    ("0x.", 0x0000000000000000, 0x00000000, 1),
+    ("0x.p1", 0x0000000000000000, 0x00000000, 1),
+    ("0xg", 0x0000000000000000, 0x00000000, 1),
+    ("0x1p", 0x3ff0000000000000, 0x3f800000, 3),
+    ("0x1p+", 0x3ff0000000000000, 0x3f800000, 3),
+    ("0x1.8p3foo", 0x4028000000000000, 0x41400000, 7),
+    ("00x1", 0x0000000000000000, 0x00000000, 2),
+    ("0x1.fffffffffffffp1023", 0x7fefffffffffffff, 0x7f800000, 22),
+    ("0x1p-1074", 0x0000000000000001, 0x00000000, 9),
+    ("0x1p-1075", 0x0000000000000000, 0x00000000, 9),
+    ("+0xAbC.dEfP-7", 0x403579bde0000000, 0x41abcdef, 13),
+    ("0x1.000001p0", 0x3ff0000010000000, 0x3f800000, 12),
+    ("0x1.0000011p0", 0x3ff0000011000000, 0x3f800001, 13),
+    (" 0x1p128", 0x47f0000000000000, 0x7f800000, 8),
+    ("inf", 0x7ff0000000000000, 0x7f800000, 3),
+    ("INFINITY", 0x7ff0000000000000, 0x7f800000, 8),
+    ("infinit", 0x7ff0000000000000, 0x7f800000, 3),
+    ("-Inf", 0xfff0000000000000, 0xff800000, 4),
+    ("infx", 0x7ff0000000000000, 0x7f800000, 3),
+    ("infinityx", 0x7ff0000000000000, 0x7f800000, 8),
+    ("+iNfInItY", 0x7ff0000000000000, 0x7f800000, 9),
+    ("i", 0x0000000000000000, 0x00000000, 0),
+    ("in", 0x0000000000000000, 0x00000000, 0),
+    ("nan", 0x7ff8000000000000, 0x7fc00000, 3),
+    ("-NaN", 0xfff8000000000000, 0xffc00000, 4),
+    ("NaN(123)", 0x7ff800000000007b, 0x7fc0007b, 8),
+    ("nan(abc_XYZ09)", 0x7ff8000000000000, 0x7fc00000, 14),
+    ("nan(", 0x7ff8000000000000, 0x7fc00000, 3),
+    ("nan(12", 0x7ff8000000000000, 0x7fc00000, 3),
+    ("nan()", 0x7ff8000000000000, 0x7fc00000, 5),
+    ("nan(-1)", 0x7ff8000000000000, 0x7fc00000, 3),
+    ("nan(0x1f)", 0x7ff800000000001f, 0x7fc0001f, 9),
+    ("nan(010)", 0x7ff8000000000008, 0x7fc00008, 8),
+    ("nan(0x)", 0x7ff8000000000000, 0x7fc00000, 7),
+    ("nan(08)", 0x7ff8000000000000, 0x7fc00000, 7),
+    ("NAN(18446744073709551616)", 0x7fffffffffffffff, 0x7fffffff, 25),
+    ("nanx", 0x7ff8000000000000, 0x7fc00000, 3),
+    ("-nan(1) ", 0xfff8000000000001, 0xffc00001, 7),
+    ("", 0x0000000000000000, 0x00000000, 0),
+    ("   ", 0x0000000000000000, 0x00000000, 0),
+    ("abc", 0x0000000000000000, 0x00000000, 0),
+    ("+", 0x0000000000000000, 0x00000000, 0),
+    ("-", 0x0000000000000000, 0x00000000, 0),
+    (".", 0x0000000000000000, 0x00000000, 0),
+    ("-.", 0x0000000000000000, 0x00000000, 0),
+    (" +.e5", 0x0000000000000000, 0x00000000, 0),
+    ("- 1", 0x0000000000000000, 0x00000000, 0),
+    ("\u{a0}1", 0x0000000000000000, 0x00000000, 0),
+    ("e5", 0x0000000000000000, 0x00000000, 0),
+    ("1e400", 0x7ff0000000000000, 0x7f800000, 5),
+    ("-1e400", 0xfff0000000000000, 0xff800000, 6),
+    ("1e-400", 0x0000000000000000, 0x00000000, 6),
+    ("1e39", 0x48078287f49c4a1d, 0x7f800000, 4),
+    ("1e-46", 0x366244ce242c5561, 0x00000000, 5),
+    ("4.9e-324", 0x0000000000000001, 0x00000000, 8),
+    ("2.4703282292062328e-324", 0x0000000000000001, 0x00000000, 23),
+    ("2.4703282292062327e-324", 0x0000000000000000, 0x00000000, 23),
+    ("-0", 0x8000000000000000, 0x80000000, 2),
+    ("0.000000000000000000000000000000000000000000000000000000001e+57", 0x3ff0000000000000, 0x3f800000, 63),
+    ("111111111111111111111111111111", 0x45f6704f4fab27ec, 0x6fb3827a, 30),
+    ("9007199254740993", 0x4340000000000000, 0x5a000000, 16),
+    ("16777217", 0x4170000010000000, 0x4b800000, 8),
+];
+
+#[test]
+fn test_strtod_glibc() {
+    for &(s, bits64, bits32, n) in CASES {
+        let (x, n64) = parse_strtod::<f64, _>(s);
+        let (y, n32) = f32::parse_float_strtod(s.as_bytes());
+        assert_eq!((n64, n32), (n, n), "{:?}", s);
+        assert_eq!(x.to_bits(), bits64, "{:?}", s);
+        assert_eq!(y.to_bits(), bits32, "{:?}", s);
+    }
+}
+
+#[test]
+fn test_strtod_decimal() {
+    // without whitespace, hex floats and NaN payloads, this is the same as parse_partial()
+    let rng = fastrand::Rng::with_seed(0);
+    for _ in 0..10_000 {
+        let x = f64::from_bits(rng.u64(..));
+        if !x.is_finite() {
+            continue;
+        }
+        let s = format!("{:e}{}", x, if rng.bool() { "x" } else { "" });
+        let expected = fast_float::parse_partial::<f64, _>(&s).unwrap();
+        assert_eq!(parse_strtod::<f64, _>(&s), expected);
+    }
+}
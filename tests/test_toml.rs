@@ -0,0 +1,116 @@
+#![allow(
+    clippy::excessive_precision,
+    clippy::approx_constant,
+    clippy::legacy_numeric_constants
+)]
+
+use fast_float::{parse_toml, FastFloat};
+
+macro_rules! check_ok {
+    ($s:expr, $x:expr) => {
+        let s = $s;
+        assert_eq!(
+            parse_toml::<f64, _>(s).unwrap().to_bits(),
+            ($x as f64).to_bits(),
+            "{:?}",
+            s
+        );
+        assert_eq!(
+            f32::parse_float_toml(s.as_bytes()).unwrap(),
+            $x as f32,
+            "{:?}",
+            s
+        );
+    };
+}
+
+macro_rules! check_err {
+    ($s:expr) => {
+        let s = $s;
+        assert!(parse_toml::<f64, _>(s).is_err(), "{:?}", s);
+        assert!(parse_toml::<f32, _>(s).is_err(), "{:?}", s);
+    };
+}
+
+#[test]
+fn test_toml_spec() {
+    // examples from the TOML 1.0 specification
+    check_ok!("+1.0", 1.0);
+    check_ok!("3.1415", 3.1415);
+    check_ok!("-0.01", -0.01);
+    check_ok!("5e+22", 5e22);
+    check_ok!("1e06", 1e6);
+    check_ok!("-2E-2", -2e-2);
+    check_ok!("6.626e-34", 6.626e-34);
+    check_ok!("224_617.445_991_228", 224_617.445_991_228);
+    check_ok!("-0.0", -0.0);
+    check_ok!("+0.0", 0.0);
+    check_err!(".7");
+    check_err!("7.");
+    check_err!("3.e+20");
+}
+
+#[test]
+fn test_toml_special() {
+    check_ok!("inf", core::f64::INFINITY);
+    check_ok!("+inf", core::f64::INFINITY);
+    check_ok!("-inf", core::f64::NEG_INFINITY);
+    assert!(parse_toml::<f64, _>("nan").unwrap().is_nan());
+    assert!(parse_toml::<f64, _>("+nan").unwrap().is_sign_positive());
+    assert!(parse_toml::<f64, _>("-nan").unwrap().is_sign_negative());
+    assert!(parse_toml::<f32, _>("-nan").unwrap().is_nan());
+    check_err!("Inf");
+    check_err!("INF");
+    check_err!("NaN");
+    check_err!("infinity");
+    check_err!("inf_");
+    check_err!("+-inf");
+    check_err!("nan(1)");
+}
+
+#[test]
+fn test_toml_underscores() {
+    check_ok!("1_0.0_1", 10.01);
+    check_ok!("1e1_0", 1e10);
+    check_ok!("1.5e-0_1", 0.15);
+    check_err!("_1.0");
+    check_err!("1_.0");
+    check_err!("1._0");
+    check_err!("1.0_");
+    check_err!("1__0.0");
+    check_err!("1.0e_1");
+    check_err!("1.0e+_1");
+    check_err!("1.0e1_");
+    check_err!("1_e1");
+}
+
+#[test]
+fn test_toml_leading_zeros() {
+    check_ok!("0.5", 0.5);
+    check_ok!("0e0", 0.);
+    check_ok!("0.000_1", 0.0001);
+    check_ok!("1e-005", 1e-5);
+    check_err!("00.5");
+    check_err!("01.5");
+    check_err!("-01e2");
+    check_err!("0_1.5");
+    check_err!("+00e0");
+}
+
+#[test]
+fn test_toml_errors() {
+    check_err!("");
+    check_err!("+");
+    check_err!("-");
+    check_err!("0");
+    check_err!("42");
+    check_err!("-1_000");
+    check_err!("1e");
+    check_err!("1e+");
+    check_err!("e5");
+    check_err!(" 1.5");
+    check_err!("1.5 ");
+    check_err!("1.5.0");
+    check_err!("0x1p0");
+    check_err!("1,5");
+}
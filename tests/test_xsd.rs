@@ -0,0 +1,97 @@
+#![allow(clippy::excessive_precision, clippy::legacy_numeric_constants)]
+
+use fast_float::{parse_xsd, FastFloat};
+
+macro_rules! check_ok {
+    ($s:expr, $x:expr) => {
+        let s = $s;
+        assert_eq!(
+            parse_xsd::<f64, _>(s).unwrap().to_bits(),
+            ($x as f64).to_bits(),
+            "{:?}",
+            s
+        );
+        assert_eq!(
+            f32::parse_float_xsd(s.as_bytes()).unwrap(),
+            $x as f32,
+            "{:?}",
+            s
+        );
+    };
+}
+
+macro_rules! check_err {
+    ($s:expr) => {
+        let s = $s;
+        assert!(parse_xsd::<f64, _>(s).is_err(), "{:?}", s);
+        assert!(parse_xsd::<f32, _>(s).is_err(), "{:?}", s);
+    };
+}
+
+#[test]
+fn test_xsd_spec() {
+    // examples from the definition of xsd:double in XML Schema 1.1 Part 2
+    check_ok!("-1E4", -1e4);
+    check_ok!("1267.43233E12", 1267.43233e12);
+    check_ok!("12.78e-2", 12.78e-2);
+    check_ok!("12", 12.);
+    check_ok!("-0", -0.);
+    check_ok!("0", 0.);
+    check_ok!("1.", 1.);
+    check_ok!(".5", 0.5);
+    check_ok!("+.5e+1", 5.);
+    check_ok!("00012.50", 12.5);
+}
+
+#[test]
+fn test_xsd_special() {
+    check_ok!("INF", core::f64::INFINITY);
+    check_ok!("+INF", core::f64::INFINITY);
+    check_ok!("-INF", core::f64::NEG_INFINITY);
+    check_ok!(" -INF\t", core::f64::NEG_INFINITY);
+    assert!(parse_xsd::<f64, _>("NaN").unwrap().is_nan());
+    assert!(parse_xsd::<f32, _>("\nNaN\r\n").unwrap().is_nan());
+    check_err!("inf");
+    check_err!("Inf");
+    check_err!("INFINITY");
+    check_err!("Infinity");
+    check_err!("+Infinity");
+    check_err!("nan");
+    check_err!("NAN");
+    check_err!("+NaN");
+    check_err!("-NaN");
+    check_err!("- INF");
+}
+
+#[test]
+fn test_xsd_whitespace() {
+    check_ok!(" 1.5", 1.5);
+    check_ok!("1.5 ", 1.5);
+    check_ok!("\t\n\r 1.5 \r\n\t", 1.5);
+    check_err!("1 .5");
+    check_err!("- 1.5");
+    check_err!("1.5e 3");
+    check_err!("\u{b}1.5");
+    check_err!("\u{c}1.5");
+    check_err!("\u{a0}1.5");
+    check_err!("1.5\u{2003}");
+}
+
+#[test]
+fn test_xsd_errors() {
+    check_err!("");
+    check_err!("  ");
+    check_err!(".");
+    check_err!("+");
+    check_err!("-.");
+    check_err!("e5");
+    check_err!("1e");
+    check_err!("1e+");
+    check_err!("1e1.5");
+    check_err!("1_000");
+    check_err!("1,5");
+    check_err!("0x1p0");
+    check_err!("1.5.0");
+    check_err!("++1");
+    check_err!("1.5d");
+}
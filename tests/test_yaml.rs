@@ -0,0 +1,91 @@
+#![allow(clippy::legacy_numeric_constants)]
+
+use fast_float::{parse_yaml, FastFloat};
+
+macro_rules! check_ok {
+    ($s:expr, $x:expr) => {
+        let s = $s;
+        assert_eq!(
+            parse_yaml::<f64, _>(s).unwrap().to_bits(),
+            ($x as f64).to_bits(),
+            "{:?}",
+            s
+        );
+        assert_eq!(
+            f32::parse_float_yaml(s.as_bytes()).unwrap(),
+            $x as f32,
+            "{:?}",
+            s
+        );
+    };
+}
+
+macro_rules! check_err {
+    ($s:expr) => {
+        let s = $s;
+        assert!(parse_yaml::<f64, _>(s).is_err(), "{:?}", s);
+        assert!(parse_yaml::<f32, _>(s).is_err(), "{:?}", s);
+    };
+}
+
+#[test]
+fn test_yaml_spec() {
+    check_ok!("1.", 1.);
+    check_ok!("-1", -1.);
+    check_ok!("+12e03", 12e3);
+    check_ok!("-2E+05", -2e5);
+    check_ok!("0.", 0.);
+    check_ok!("-0.0", -0.);
+    check_ok!(".5", 0.5);
+    check_ok!("1.e5", 1e5);
+    check_ok!("-.5e-1", -0.05);
+    check_ok!("007.5", 7.5);
+}
+
+#[test]
+fn test_yaml_special() {
+    check_ok!(".inf", core::f64::INFINITY);
+    check_ok!(".Inf", core::f64::INFINITY);
+    check_ok!("+.INF", core::f64::INFINITY);
+    check_ok!("-.inf", core::f64::NEG_INFINITY);
+    check_ok!("-.Inf", core::f64::NEG_INFINITY);
+    for &s in &[".nan", ".NaN", ".NAN"] {
+        assert!(parse_yaml::<f64, _>(s).unwrap().is_nan());
+        assert!(parse_yaml::<f32, _>(s).unwrap().is_nan());
+    }
+    check_err!("inf");
+    check_err!("nan");
+    check_err!("-inf");
+    check_err!("infinity");
+    check_err!(".infinity");
+    check_err!(".iNf");
+    check_err!(".INf");
+    check_err!(".Nan");
+    check_err!(".nAn");
+    check_err!("-.nan");
+    check_err!("+.NaN");
+    check_err!("..inf");
+    check_err!(".inf ");
+}
+
+#[test]
+fn test_yaml_errors() {
+    check_err!("");
+    check_err!(".");
+    check_err!("+");
+    check_err!("-.");
+    check_err!(".e5");
+    check_err!("e5");
+    check_err!("1e");
+    check_err!("1e+");
+    check_err!("1_000.5");
+    check_err!("1,5");
+    check_err!("0x1p0");
+    check_err!("0x10");
+    check_err!(" 1.5");
+    check_err!("1.5 ");
+    check_err!("1.5.0");
+    check_err!("+-1.5");
+    check_err!("1.5f");
+    check_err!("~");
+}